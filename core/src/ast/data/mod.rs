@@ -57,6 +57,22 @@ impl<V, F> Data<V, F> {
         }
     }
 
+    /// Applies `variant_fn` to each variant if this is an enum, or `field_fn` to each field
+    /// if this is a struct.
+    ///
+    /// `V` is opaque to `Data`, so fields inside enum variants are not visited by `field_fn`;
+    /// `variant_fn` should map those if needed.
+    pub fn map<V2, F2, MV, MF>(self, variant_fn: MV, field_fn: MF) -> Data<V2, F2>
+    where
+        MV: FnMut(V) -> V2,
+        MF: FnMut(F) -> F2,
+    {
+        match self {
+            Data::Enum(v) => Data::Enum(v.into_iter().map(variant_fn).collect()),
+            Data::Struct(f) => Data::Struct(f.map(field_fn)),
+        }
+    }
+
     /// Applies a function `V -> U` on enum variants, if this is an enum.
     pub fn map_enum_variants<T, U>(self, map: T) -> Data<U, F>
     where
@@ -243,6 +259,11 @@ impl<T> Fields<T> {
         self.style.is_struct()
     }
 
+    /// Returns `true` if this is a tuple with exactly one field, such as `struct Meters(f64);`.
+    pub fn is_newtype(&self) -> bool {
+        self.style.is_tuple() && self.fields.len() == 1
+    }
+
     pub fn as_ref(&self) -> Fields<&T> {
         Fields {
             style: self.style,
@@ -268,6 +289,11 @@ impl<T> Fields<T> {
         self.fields.iter()
     }
 
+    /// Returns an iterator that allows modifying each field in place.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.fields.iter_mut()
+    }
+
     /// Returns the number of fields in the structure.
     pub fn len(&self) -> usize {
         self.fields.len()
@@ -287,8 +313,9 @@ impl<F: FromField> Fields<F> {
                 syn::Fields::Named(fields) => fields
                     .named
                     .iter()
-                    .filter_map(|field| {
-                        errors.handle(FromField::from_field(field).map_err(|err| {
+                    .enumerate()
+                    .filter_map(|(index, field)| {
+                        errors.handle(FromField::from_field_at(field, index).map_err(|err| {
                             // There should always be an ident here, since this is a collection
                             // of named fields, but `syn` doesn't prevent someone from manually
                             // constructing an invalid collection so a guard is still warranted.
@@ -303,7 +330,10 @@ impl<F: FromField> Fields<F> {
                 syn::Fields::Unnamed(fields) => fields
                     .unnamed
                     .iter()
-                    .filter_map(|field| errors.handle(FromField::from_field(field)))
+                    .enumerate()
+                    .filter_map(|(index, field)| {
+                        errors.handle(FromField::from_field_at(field, index))
+                    })
                     .collect(),
                 syn::Fields::Unit => vec![],
             }
@@ -484,6 +514,90 @@ mod tests {
         assert_eq!(result.to_string(), reference.to_string());
     }
 
+    #[test]
+    fn is_newtype() {
+        let unit = token_stream_to_fields(quote!(
+            struct Example;
+        ));
+        assert!(!unit.is_newtype());
+        assert!(unit.is_empty());
+
+        let newtype = token_stream_to_fields(quote!(
+            struct Example(u8);
+        ));
+        assert!(newtype.is_newtype());
+        assert_eq!(newtype.len(), 1);
+
+        let tuple = token_stream_to_fields(quote!(
+            struct Example(u8, u16);
+        ));
+        assert!(!tuple.is_newtype());
+        assert_eq!(tuple.len(), 2);
+
+        let named = token_stream_to_fields(quote!(
+            struct Example {
+                a: u8,
+            }
+        ));
+        assert!(!named.is_newtype());
+        assert_eq!(named.len(), 1);
+    }
+
+    #[test]
+    fn data_map_struct() {
+        let data: Data<u8, u8> = Data::Struct(Fields::new(Style::Tuple, vec![1, 2]));
+        let mapped: Data<String, u16> = data.map(|v| v.to_string(), |f| u16::from(f) * 100);
+        assert_eq!(
+            mapped,
+            Data::Struct(Fields::new(Style::Tuple, vec![100, 200]))
+        );
+    }
+
+    #[test]
+    fn data_map_enum() {
+        let data: Data<u8, u8> = Data::Enum(vec![1, 2]);
+        let mapped: Data<String, u16> = data.map(|v| v.to_string(), |f| u16::from(f) * 100);
+        assert_eq!(mapped, Data::Enum(vec!["1".to_string(), "2".to_string()]));
+    }
+
+    #[test]
+    fn map_preserves_style_and_span() {
+        for input in [
+            quote!(
+                struct Example;
+            ),
+            quote!(
+                struct Example(u8, u16);
+            ),
+            quote!(
+                struct Example {
+                    a: u8,
+                    b: u16,
+                }
+            ),
+        ] {
+            let fields = token_stream_to_fields(input);
+            let style = fields.style;
+            let len = fields.len();
+            let mapped = fields.map(|field| field.ty.to_token_stream().to_string());
+
+            assert_eq!(mapped.style, style);
+            assert_eq!(mapped.len(), len);
+            assert!(mapped.span.is_some());
+        }
+    }
+
+    #[test]
+    fn iter_mut_modifies_fields() {
+        let mut fields = Fields::new(Style::Tuple, vec![1, 2, 3]);
+        for field in fields.iter_mut() {
+            *field *= 10;
+        }
+
+        assert_eq!(fields.fields, vec![10, 20, 30]);
+        assert!(fields.is_tuple());
+    }
+
     #[test]
     fn test_fields_to_tokens_tuple() {
         let reference = quote!((u64, usize, &'a T));
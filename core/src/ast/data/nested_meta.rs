@@ -227,6 +227,41 @@ impl NestedMeta {
             .parse2(tokens)
             .map(|punctuated| punctuated.into_iter().collect())
     }
+
+    /// Returns the path if this is a bare word, such as `foo` in `#[attr(foo)]`.
+    pub fn as_path(&self) -> Option<&syn::Path> {
+        match self {
+            NestedMeta::Meta(Meta::Path(path)) => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Returns the name-value pair if this is `foo = ...` with a valid expression on the right.
+    ///
+    /// Name-value items whose right-hand side isn't an expression are stored as
+    /// [`NestedMeta::NameValueInvalidExpr`] and return `None` here.
+    pub fn as_name_value(&self) -> Option<&syn::MetaNameValue> {
+        match self {
+            NestedMeta::Meta(Meta::NameValue(nv)) => Some(nv),
+            _ => None,
+        }
+    }
+
+    /// Returns the list if this is `foo(...)`.
+    pub fn as_list(&self) -> Option<&syn::MetaList> {
+        match self {
+            NestedMeta::Meta(Meta::List(list)) => Some(list),
+            _ => None,
+        }
+    }
+
+    /// Returns the literal if this is a bare literal, such as `"foo"` in `#[attr("foo")]`.
+    pub fn as_lit(&self) -> Option<&syn::Lit> {
+        match self {
+            NestedMeta::Lit(lit) => Some(lit),
+            _ => None,
+        }
+    }
 }
 
 impl syn::parse::Parse for NestedMeta {
@@ -287,4 +322,61 @@ mod tests {
             NestedMeta::Meta(Meta::Path(parse_quote!(::prost::Message)))
         );
     }
+
+    #[test]
+    fn classify_path() {
+        let input: NestedMeta = parse_quote!(foo::bar);
+        assert_eq!(input.as_path(), Some(&parse_quote!(foo::bar)));
+        assert!(input.as_name_value().is_none());
+        assert!(input.as_list().is_none());
+        assert!(input.as_lit().is_none());
+    }
+
+    #[test]
+    fn classify_name_value() {
+        let input: NestedMeta = parse_quote!(foo = "bar");
+        let nv = input.as_name_value().unwrap();
+        assert!(nv.path.is_ident("foo"));
+        assert_eq!(nv.value, parse_quote!("bar"));
+        assert!(input.as_path().is_none());
+        assert!(input.as_list().is_none());
+        assert!(input.as_lit().is_none());
+    }
+
+    #[test]
+    fn classify_list() {
+        let input: NestedMeta = parse_quote!(foo(bar, baz = 1));
+        let list = input.as_list().unwrap();
+        assert!(list.path.is_ident("foo"));
+        assert_eq!(
+            NestedMeta::parse_meta_list(list.tokens.clone())
+                .unwrap()
+                .len(),
+            2
+        );
+        assert!(input.as_path().is_none());
+        assert!(input.as_name_value().is_none());
+        assert!(input.as_lit().is_none());
+    }
+
+    #[test]
+    fn classify_lit() {
+        for input in [parse_quote!("foo"), parse_quote!(42), parse_quote!(true)] {
+            let input: NestedMeta = input;
+            assert!(input.as_lit().is_some());
+            assert!(input.as_path().is_none());
+            assert!(input.as_name_value().is_none());
+            assert!(input.as_list().is_none());
+        }
+    }
+
+    #[test]
+    fn classify_invalid_expr() {
+        let input: NestedMeta = parse_quote!(vis = pub(crate));
+        assert!(matches!(input, NestedMeta::NameValueInvalidExpr(_)));
+        assert!(input.as_name_value().is_none());
+        assert!(input.as_path().is_none());
+        assert!(input.as_list().is_none());
+        assert!(input.as_lit().is_none());
+    }
 }
@@ -3,6 +3,9 @@
 use std::iter::Iterator;
 use std::slice::Iter;
 
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+
 use crate::{FromGenericParam, FromGenerics, FromTypeParam, Result};
 
 /// Extension trait for `GenericParam` to support getting values by variant.
@@ -131,6 +134,21 @@ impl<T, L, C> GenericParamExt for GenericParam<T, L, C> {
     }
 }
 
+impl<T, L, C> From<GenericParam<T, L, C>> for syn::GenericParam
+where
+    T: Into<syn::TypeParam>,
+    L: Into<syn::LifetimeParam>,
+    C: Into<syn::ConstParam>,
+{
+    fn from(param: GenericParam<T, L, C>) -> Self {
+        match param {
+            GenericParam::Type(val) => syn::GenericParam::Type(val.into()),
+            GenericParam::Lifetime(val) => syn::GenericParam::Lifetime(val.into()),
+            GenericParam::Const(val) => syn::GenericParam::Const(val.into()),
+        }
+    }
+}
+
 /// A mirror of the `syn::Generics` type which can contain arbitrary representations
 /// of params and where clauses.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -145,6 +163,37 @@ impl<P, W> Generics<P, W> {
     }
 }
 
+impl<P, W> Generics<P, W>
+where
+    P: Clone + Into<syn::GenericParam>,
+    W: Clone + Into<syn::WhereClause>,
+{
+    /// Split the generics into the pieces needed to write an impl, as with
+    /// [`syn::Generics::split_for_impl`]. The result is `(impl_generics, ty_generics, where_clause)`,
+    /// any of which may be empty.
+    ///
+    /// ```rust,ignore
+    /// let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+    /// quote!(impl #impl_generics MyTrait for #ident #ty_generics #where_clause {})
+    /// ```
+    pub fn split_for_impl(&self) -> (TokenStream, TokenStream, TokenStream) {
+        let params = self.params.iter().cloned().map(Into::into).collect();
+        let generics = syn::Generics {
+            lt_token: Some(Default::default()),
+            params,
+            gt_token: Some(Default::default()),
+            where_clause: self.where_clause.clone().map(Into::into),
+        };
+
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        (
+            impl_generics.into_token_stream(),
+            ty_generics.into_token_stream(),
+            where_clause.into_token_stream(),
+        )
+    }
+}
+
 impl<P: FromGenericParam> FromGenerics for Generics<P> {
     fn from_generics(generics: &syn::Generics) -> Result<Self> {
         Ok(Generics {
@@ -179,8 +228,52 @@ impl<'a, P: GenericParamExt> Iterator for TypeParams<'a, P> {
 mod tests {
     use syn::parse_quote;
 
+    use quote::ToTokens;
+
     use super::{GenericParam, Generics};
-    use crate::FromGenerics;
+    use crate::{FromGenericParam, FromGenerics};
+
+    fn assert_split_matches<P>(g: &syn::Generics)
+    where
+        P: FromGenericParam + Clone + Into<syn::GenericParam>,
+    {
+        let deified: Generics<P> = FromGenerics::from_generics(g).unwrap();
+        let (impl_generics, ty_generics, where_clause) = deified.split_for_impl();
+        let (expected_impl, expected_ty, expected_where) = g.split_for_impl();
+        assert_eq!(
+            impl_generics.to_string(),
+            expected_impl.to_token_stream().to_string()
+        );
+        assert_eq!(
+            ty_generics.to_string(),
+            expected_ty.to_token_stream().to_string()
+        );
+        assert_eq!(
+            where_clause.to_string(),
+            expected_where.to_token_stream().to_string()
+        );
+    }
+
+    #[test]
+    fn split_for_impl() {
+        let g: syn::Generics =
+            parse_quote!(<'a, 'b: 'a, T: Clone + 'a = String, const N: usize = 3>);
+        let mut g = g;
+        g.where_clause = Some(parse_quote!(where T: Default, [u8; N]: Copy));
+
+        assert_split_matches::<GenericParam>(&g);
+        assert_split_matches::<syn::GenericParam>(&g);
+    }
+
+    #[test]
+    fn split_for_impl_empty() {
+        let g: syn::Generics = parse_quote!();
+        let deified: Generics<GenericParam> = FromGenerics::from_generics(&g).unwrap();
+        let (impl_generics, ty_generics, where_clause) = deified.split_for_impl();
+        assert!(impl_generics.is_empty());
+        assert!(ty_generics.is_empty());
+        assert!(where_clause.is_empty());
+    }
 
     #[test]
     fn generics() {
@@ -1,5 +1,6 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::{parse_quote, Ident};
 
 use crate::util::PathList;
 
@@ -15,6 +16,20 @@ pub trait ExtractAttribute {
 
     fn forward_attrs(&self) -> &ForwardAttrs<'_>;
 
+    /// Gets the field which should receive the span of the first matched attribute, if any.
+    fn attr_span(&self) -> Option<&Ident> {
+        None
+    }
+
+    /// Gets the initializer for the `attr_span` field, which falls back to the span of the
+    /// input item when no attribute matched.
+    fn attr_span_field_value(&self) -> Option<syn::FieldValue> {
+        let input = self.param_name();
+        self.attr_span().map(|ident| {
+            parse_quote!(#ident: __attr_span.unwrap_or_else(|| _darling::export::syn::spanned::Spanned::span(#input)))
+        })
+    }
+
     /// Gets the name used by the generated impl to return to the `syn` item passed as input.
     fn param_name(&self) -> TokenStream;
 
@@ -36,6 +51,10 @@ pub trait ExtractAttribute {
             .as_declaration()
             .to_tokens(&mut declarations);
 
+        if self.attr_span().is_some() {
+            declarations.extend(quote!(let mut __attr_span = _darling::export::None;));
+        }
+
         let will_parse_any = !self.attr_names().is_empty();
 
         // Forwarding requires both that there be some items we would forward,
@@ -55,8 +74,17 @@ pub trait ExtractAttribute {
         let parse_handled = if will_parse_any {
             let attr_names = self.attr_names().to_strings();
             let core_loop = self.core_loop();
+            let record_span = self.attr_span().map(|_| {
+                quote! {
+                    if __attr_span.is_none() {
+                        __attr_span = _darling::export::Some(_darling::export::syn::spanned::Spanned::span(__attr));
+                    }
+                }
+            });
             quote!(
                 #(#attr_names)|* => {
+                    #record_span
+
                     match _darling::util::parse_attribute_to_meta_list(__attr) {
                         _darling::export::Ok(__data) => {
                             match _darling::export::NestedMeta::parse_meta_list(__data.tokens) {
@@ -15,6 +15,9 @@ pub enum DefaultExpression<'a> {
     Inherit(&'a Ident),
     /// `default = path::to::function` or `default = || default_val()`.
     Explicit(&'a Callable),
+    /// `default_with = path::to::function`. Only valid on fields, this expands to a call
+    /// returning `darling::Result<T>`, so its error can be added to the accumulator.
+    Fallible(&'a Callable),
     Trait {
         span: Span,
     },
@@ -24,6 +27,11 @@ impl<'a> DefaultExpression<'a> {
     pub fn as_declaration(&'a self) -> DefaultDeclaration<'a> {
         DefaultDeclaration(self)
     }
+
+    /// Whether the expression produces a `darling::Result` rather than the value itself.
+    pub fn is_fallible(&self) -> bool {
+        matches!(self, DefaultExpression::Fallible(_))
+    }
 }
 
 impl ToTokens for DefaultExpression<'_> {
@@ -39,6 +47,11 @@ impl ToTokens for DefaultExpression<'_> {
                     _darling::export::identity::<fn() -> _>(#callable)()
                 )
             }
+            DefaultExpression::Fallible(callable) => {
+                quote_spanned!(callable.span()=>
+                    _darling::export::identity::<fn() -> _darling::Result<_>>(#callable)()
+                )
+            }
             DefaultExpression::Trait { span } => {
                 quote_spanned!(span=> _darling::export::Default::default())
             }
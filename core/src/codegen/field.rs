@@ -1,11 +1,13 @@
 use std::borrow::Cow;
 
 use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
+use quote::{format_ident, quote, quote_spanned, ToTokens, TokenStreamExt};
 use syn::{spanned::Spanned, Ident, Type};
 
 use crate::codegen::{DefaultExpression, PostfixTransform};
+use crate::options::Dedup;
 use crate::usage::{self, IdentRefSet, IdentSet, UsesTypeParams};
+use crate::util::Callable;
 
 /// Properties needed to generate code for a field in all the contexts
 /// where one may appear.
@@ -15,6 +17,13 @@ pub struct Field<'a> {
     /// in error messages and will be looked when parsing names.
     pub name_in_attr: Cow<'a, str>,
 
+    /// Additional names which are also matched against input.
+    pub aliases: &'a [String],
+
+    /// Deprecated names which are also matched against input. Using one of these
+    /// records a warning that points to the canonical name.
+    pub deprecated_aliases: &'a [String],
+
     /// The name presented to the author of the library. This will appear
     /// in the setters or temporary variables which contain the values.
     pub ident: &'a Ident,
@@ -22,31 +31,73 @@ pub struct Field<'a> {
     /// The type of the field in the input.
     pub ty: &'a Type,
     pub default_expression: Option<DefaultExpression<'a>>,
+    /// Replaces the text of the missing field error, which otherwise names the field.
+    pub missing_message: Option<&'a str>,
+    /// An environment variable read at expansion time when the field is absent. Its
+    /// value is parsed with `FromMeta::from_string` before any other default is tried.
+    pub default_env: Option<&'a str>,
     /// An expression that will be wrapped in a call to [`core::convert::identity`] and
     /// then used for converting a provided value into the field value _before_ postfix
     /// transforms are called.
     pub with_callable: Cow<'a, syn::Expr>,
-    pub post_transform: Option<&'a PostfixTransform>,
+    /// Transforms applied, in order, to the value produced by `with_callable`.
+    pub post_transform: &'a [PostfixTransform],
     pub skip: bool,
+    /// A `fn() -> bool` deciding at parse time whether the field is skipped. A skipped
+    /// field falls back to its default, and its presence in the input is an error.
+    pub skip_if: Option<&'a Callable>,
     pub multiple: bool,
+    /// How a `multiple` field handles a value it already holds. When `None`, every value
+    /// is pushed.
+    pub dedup: Option<Dedup>,
+    /// If set, an explicitly empty list or array is rejected before parsing.
+    pub deny_empty: bool,
     /// If set, this field will be given all unclaimed meta items and will
     /// not be exposed as a standard named field.
     pub flatten: bool,
+    /// If set, this field will be given all unclaimed meta items keyed by their path,
+    /// and will not be exposed as a standard named field.
+    pub rest: bool,
+    /// If set, this field is read from a leading positional item rather than by name.
+    pub positional: bool,
+    /// If set, this field will be given the warnings recorded while parsing, and will
+    /// not be exposed as a standard named field.
+    pub warnings: bool,
 }
 
 impl<'a> Field<'a> {
     /// Get the name of the meta item that should be matched against input and should be used in diagnostics.
     ///
-    /// This will be `None` if the field is `skip` or `flatten`, as neither kind of field is addressable
-    /// by name from the input meta.
+    /// This will be `None` if the field is `skip`, `flatten`, `rest`, `positional`, or `warnings`,
+    /// as none of those kinds of field are addressable by name from the input meta.
     pub fn as_name(&'a self) -> Option<&'a str> {
-        if self.skip || self.flatten {
+        if self.skip || self.flatten || self.rest || self.positional || self.warnings {
             None
         } else {
             Some(&self.name_in_attr)
         }
     }
 
+    /// Get the local variable which records where a single-value field was first set, so
+    /// that a duplicate can point at both occurrences. This is `None` for fields that are
+    /// not matched by name or that accept repeats.
+    fn first_span_ident(&self) -> Option<Ident> {
+        if self.as_name().is_none() || self.multiple {
+            None
+        } else {
+            Some(format_ident!("__{}_first_span", self.ident))
+        }
+    }
+
+    /// Get the call to the `skip_if` predicate, if there is one.
+    fn skip_if_call(&self) -> Option<TokenStream> {
+        self.skip_if.map(|callable| {
+            quote_spanned!(callable.span()=>
+                _darling::export::identity::<fn() -> bool>(#callable)()
+            )
+        })
+    }
+
     pub fn as_declaration(&'a self) -> Declaration<'a> {
         Declaration(self)
     }
@@ -61,10 +112,22 @@ impl<'a> Field<'a> {
         }
     }
 
+    pub fn as_rest_initializer(&'a self) -> RestInitializer<'a> {
+        RestInitializer(self)
+    }
+
+    pub fn as_warnings_initializer(&'a self) -> WarningsInitializer<'a> {
+        WarningsInitializer(self)
+    }
+
     pub fn as_match(&'a self) -> MatchArm<'a> {
         MatchArm(self)
     }
 
+    pub fn as_positional(&'a self) -> Positional<'a> {
+        Positional(self)
+    }
+
     pub fn as_initializer(&'a self) -> Initializer<'a> {
         Initializer(self)
     }
@@ -100,6 +163,12 @@ impl ToTokens for Declaration<'_> {
             quote!(let mut #ident: (bool, _darling::export::Option<#ty>) = (false, None);)
         });
 
+        if let Some(first_span) = field.first_span_ident() {
+            tokens.append_all(quote! {
+                let mut #first_span = _darling::export::None;
+            });
+        }
+
         // The flatten field additionally needs a place to buffer meta items
         // until attribute walking is done, so declare that now.
         //
@@ -110,6 +179,23 @@ impl ToTokens for Declaration<'_> {
                 let mut __flatten = _darling::export::Vec::<_darling::ast::NestedMeta>::new();
             });
         }
+
+        // Likewise, the rest field collects unknown meta items and their names.
+        if field.rest {
+            tokens.append_all(quote! {
+                let mut __rest = _darling::export::Vec::<(
+                    _darling::export::String,
+                    _darling::export::syn::Meta,
+                )>::new();
+            });
+        }
+
+        // The warnings field collects non-fatal diagnostics raised during the walk.
+        if field.warnings {
+            tokens.append_all(quote! {
+                let mut __warnings = _darling::export::Vec::<_darling::Error>::new();
+            });
+        }
     }
 }
 
@@ -144,6 +230,34 @@ impl ToTokens for FlattenInitializer<'_> {
     }
 }
 
+/// Moves the collected unknown meta items into the `rest` field.
+pub struct RestInitializer<'a>(&'a Field<'a>);
+
+impl ToTokens for RestInitializer<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ident = self.0.ident;
+        tokens.append_all(quote! {
+            #ident = (true, _darling::export::Some(
+                _darling::export::IntoIterator::into_iter(__rest).collect()
+            ));
+        });
+    }
+}
+
+/// Moves the recorded warnings into the `warnings` field.
+pub struct WarningsInitializer<'a>(&'a Field<'a>);
+
+impl ToTokens for WarningsInitializer<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ident = self.0.ident;
+        tokens.append_all(quote! {
+            #ident = (true, _darling::export::Some(
+                _darling::export::IntoIterator::into_iter(__warnings).collect()
+            ));
+        });
+    }
+}
+
 /// Represents an individual field in the match.
 pub struct MatchArm<'a>(&'a Field<'a>);
 
@@ -151,16 +265,18 @@ impl ToTokens for MatchArm<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let field = self.0;
 
-        // Skipped and flattened fields cannot be populated by a meta
-        // with their name, so they do not have a match arm.
-        if field.skip || field.flatten {
+        // Skipped, flattened, rest, positional, and warnings fields cannot be populated by
+        // a meta with their name, so they do not have a match arm.
+        if field.skip || field.flatten || field.rest || field.positional || field.warnings {
             return;
         }
 
         let name_str = &field.name_in_attr;
+        let aliases = field.aliases;
+        let deprecated_aliases = field.deprecated_aliases;
         let ident = field.ident;
         let with_callable = &field.with_callable;
-        let post_transform = field.post_transform.as_ref();
+        let post_transform = field.post_transform;
 
         // Errors include the location of the bad input, so we compute that here.
         // Fields that take multiple values add the index of the error for convenience,
@@ -192,32 +308,157 @@ impl ToTokens for MatchArm<'_> {
                 },
                 _ => unreachable!()
             }
-            #post_transform
+            #(#post_transform)*
             .map_err(|e| e.with_span(&__item).at(#location))
         );
 
-        tokens.append_all(if field.multiple {
-                quote!(
-                    #name_str => {
-                        // Store the index of the name we're assessing in case we need
-                        // it for error reporting.
-                        let __len = #ident.len();
-                        if let _darling::export::Some(__val) = __errors.handle(#extractor) {
-                            #ident.push(__val)
-                        }
+        // An empty list such as `foo()` or array such as `foo = []` is rejected without
+        // consulting the field's type, so this works for any collection.
+        let extractor = if field.deny_empty {
+            quote! {{
+                let __empty = match *__item {
+                    _darling::export::NestedMeta::Meta(_darling::export::syn::Meta::List(ref __list))
+                        if __list.tokens.is_empty() => true,
+                    _darling::export::NestedMeta::Meta(_darling::export::syn::Meta::NameValue(
+                        _darling::export::syn::MetaNameValue {
+                            value: _darling::export::syn::Expr::Array(ref __array),
+                            ..
+                        },
+                    )) => __array.elems.is_empty(),
+                    _ => false,
+                };
+
+                if __empty {
+                    _darling::export::Err(
+                        _darling::Error::custom("expected at least one value")
+                            .with_span(&__item)
+                            .at(#location),
+                    )
+                } else {
+                    #extractor
+                }
+            }}
+        } else {
+            extractor
+        };
+
+        // The canonical name and all aliases share a match arm; if a deprecated alias
+        // was used, record a warning pointing at the input name. The container is required
+        // to have a `warnings` field whenever a deprecated alias exists.
+        let pattern = quote!(#name_str #(| #aliases)* #(| #deprecated_aliases)*);
+        let warn_alias = if deprecated_aliases.is_empty() {
+            quote!()
+        } else {
+            quote! {
+                if let #(#deprecated_aliases)|* = __name.as_str() {
+                    __warnings.push(
+                        _darling::Error::custom(format!(
+                            "`{}` is deprecated; use `{}` instead",
+                            __name, #name_str
+                        ))
+                        .with_span(__path),
+                    );
+                }
+            }
+        };
+
+        let arm = if field.multiple {
+            // Deduplicating fields may be sets, so they're filled through `Extend`
+            // rather than `push`.
+            let add = match field.dedup {
+                None => quote!(#ident.push(__val)),
+                Some(Dedup::Ignore) => quote! {
+                    if !#ident.contains(&__val) {
+                        _darling::export::Extend::extend(&mut #ident, _darling::export::Some(__val));
                     }
-                )
-            } else {
-                quote!(
-                    #name_str => {
-                        if !#ident.0 {
-                            #ident = (true, __errors.handle(#extractor));
-                        } else {
-                            __errors.push(_darling::Error::duplicate_field(#name_str).with_span(&__item));
+                },
+                Some(Dedup::Error) => quote! {
+                    if #ident.contains(&__val) {
+                        __errors.push(
+                            _darling::Error::custom(format!("duplicate value for `{}`", #name_str))
+                                .with_span(&__item),
+                        );
+                    } else {
+                        _darling::export::Extend::extend(&mut #ident, _darling::export::Some(__val));
+                    }
+                },
+            };
+            quote!(
+                {
+                    #warn_alias
+                    // Store the index of the name we're assessing in case we need
+                    // it for error reporting.
+                    let __len = #ident.len();
+                    if let _darling::export::Some(__val) = __errors.handle(#extractor) {
+                        #add
+                    }
+                }
+            )
+        } else {
+            let first_span = field
+                .first_span_ident()
+                .expect("fields with a match arm that aren't `multiple` track their first span");
+            quote!(
+                {
+                    #warn_alias
+                    if !#ident.0 {
+                        #ident = (true, __errors.handle(#extractor));
+                        #first_span = _darling::export::Some(_darling::export::syn::spanned::Spanned::span(__item));
+                    } else {
+                        // Report the first occurrence once, then every repeat.
+                        if let _darling::export::Some(__first) = #first_span.take() {
+                            __errors.push(_darling::Error::duplicate_field(#name_str).with_span(&__first));
                         }
+                        __errors.push(_darling::Error::duplicate_field(#name_str).with_span(&__item));
                     }
-                )
-            });
+                }
+            )
+        };
+
+        // A field whose `skip_if` predicate holds must not be set by the caller.
+        tokens.append_all(if let Some(skip_if) = field.skip_if_call() {
+            quote! {
+                #pattern => {
+                    if #skip_if {
+                        __errors.push(
+                            _darling::Error::custom(format!("field `{}` is not available", #name_str))
+                                .with_span(&__item)
+                        );
+                    } else #arm
+                }
+            }
+        } else {
+            quote!(#pattern => #arm)
+        });
+    }
+}
+
+/// Reads the next leading positional item into a `positional` field.
+///
+/// The remaining items are held in `__positional`. Only a literal is read positionally;
+/// any other item, including a bare word such as a flag, ends the positional run and
+/// leaves the field unset so that it is reported as missing.
+pub struct Positional<'a>(&'a Field<'a>);
+
+impl ToTokens for Positional<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let field = self.0;
+        let ident = field.ident;
+        let name_str = &field.name_in_attr;
+        let post_transform = field.post_transform;
+
+        tokens.append_all(quote! {
+            if let _darling::export::Some((__item, __tail)) = __positional.split_first() {
+                if let _darling::export::NestedMeta::Lit(_) = *__item {
+                    #ident = (true, __errors.handle(
+                        _darling::FromMeta::from_nested_meta(__item)
+                            #(#post_transform)*
+                            .map_err(|e| e.with_span(__item).at(#name_str))
+                    ));
+                    __positional = __tail;
+                }
+            }
+        });
     }
 }
 
@@ -228,8 +469,13 @@ impl ToTokens for Initializer<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let field = self.0;
         let ident = field.ident;
+        // Fallible defaults were already resolved while checking for missing fields.
+        let default_expression = field
+            .default_expression
+            .as_ref()
+            .filter(|expr| !expr.is_fallible());
         tokens.append_all(if field.multiple {
-            if let Some(ref expr) = field.default_expression {
+            if let Some(expr) = default_expression {
                 quote_spanned!(expr.span()=> #ident: if !#ident.is_empty() {
                     #ident
                 } else {
@@ -238,7 +484,7 @@ impl ToTokens for Initializer<'_> {
             } else {
                 quote!(#ident: #ident)
             }
-        } else if let Some(ref expr) = field.default_expression {
+        } else if let Some(expr) = default_expression {
             quote_spanned!(expr.span()=> #ident: if let Some(__val) = #ident.1 {
                 __val
             } else {
@@ -253,26 +499,118 @@ impl ToTokens for Initializer<'_> {
 /// Creates an error if a field has no value and no default.
 pub struct CheckMissing<'a>(&'a Field<'a>);
 
+impl CheckMissing<'_> {
+    /// The code which fills in a single-value field that was absent from the input, or
+    /// `None` if its default is applied when the struct is initialized.
+    fn fallback(&self) -> Option<TokenStream> {
+        let field = self.0;
+        let ident = field.ident;
+        let name_in_attr = &field.name_in_attr;
+
+        if let Some(expr) = field.default_expression.as_ref() {
+            return if expr.is_fallible() {
+                Some(quote!(#ident.1 = __errors.handle(#expr.map_err(|e| e.at(#name_in_attr)));))
+            } else {
+                None
+            };
+        }
+
+        let from_none_call = super::from_none_call(field.ty);
+        // A custom message keeps the missing field code so tooling can still recognize it.
+        let missing = match (field.missing_message, field.default_env) {
+            (Some(message), _) => quote! {
+                _darling::Error::custom(#message).with_code("darling::missing_field")
+            },
+            (None, Some(var)) => quote! {
+                _darling::Error::custom(format!(
+                    "Missing field `{}` and environment variable `{}` is not set",
+                    #name_in_attr, #var
+                ))
+                .with_code("darling::missing_field")
+            },
+            (None, None) => quote!(_darling::Error::missing_field(#name_in_attr)),
+        };
+        let check = quote! {
+            match #from_none_call {
+                _darling::export::Some(__type_fallback) => {
+                    #ident.1 = _darling::export::Some(__type_fallback);
+                }
+                _darling::export::None => {
+                    __errors.push(#missing)
+                }
+            }
+        };
+
+        // A skipped field is never missing. Without a default expression, it falls back to
+        // the type's `from_none` value, and then to `Default::default()`.
+        Some(if let Some(callable) = field.skip_if {
+            let skip_if = field.skip_if_call();
+            quote_spanned! {callable.span()=>
+                if #skip_if {
+                    #ident.1 = _darling::export::Some(
+                        #from_none_call.unwrap_or_else(_darling::export::Default::default)
+                    );
+                } else {
+                    #check
+                }
+            }
+        } else {
+            check
+        })
+    }
+}
+
 impl ToTokens for CheckMissing<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        if !self.0.multiple && self.0.default_expression.is_none() {
-            let ident = self.0.ident;
-            let ty = self.0.ty;
-            let name_in_attr = &self.0.name_in_attr;
-            let from_none_call = super::from_none_call(ty);
-
-            tokens.append_all(quote! {
-                if !#ident.0 {
-                    match #from_none_call {
-                        _darling::export::Some(__type_fallback) => {
-                            #ident.1 = _darling::export::Some(__type_fallback);
-                        }
-                        _darling::export::None => {
-                            __errors.push(_darling::Error::missing_field(#name_in_attr))
+        let field = self.0;
+        let ident = field.ident;
+        let name_in_attr = &field.name_in_attr;
+
+        if field.multiple {
+            if let Some(expr) = field
+                .default_expression
+                .as_ref()
+                .filter(|e| e.is_fallible())
+            {
+                tokens.append_all(quote! {
+                    if #ident.is_empty() {
+                        if let _darling::export::Some(__val) = __errors.handle(#expr.map_err(|e| e.at(#name_in_attr))) {
+                            #ident = __val;
                         }
                     }
-                }
-            })
+                });
+            }
+
+            return;
         }
+
+        let fallback = self.fallback();
+        let check = if let Some(var) = field.default_env {
+            let post_transform = field.post_transform;
+            quote! {
+                match _darling::export::env::var(#var) {
+                    _darling::export::Ok(__env) => {
+                        #ident.1 = __errors.handle(
+                            _darling::FromMeta::from_string(&__env)
+                                #(#post_transform)*
+                                .map_err(|e| e.at(#name_in_attr))
+                        );
+                    }
+                    _darling::export::Err(_) => {
+                        #fallback
+                    }
+                }
+            }
+        } else if let Some(fallback) = fallback {
+            fallback
+        } else {
+            return;
+        };
+
+        tokens.append_all(quote! {
+            if !#ident.0 {
+                #check
+            }
+        })
     }
 }
@@ -33,6 +33,13 @@ impl ToTokens for FromAttributesImpl<'_> {
                             #ty_ident { #member: _darling::FromAttributes::from_attributes(#input)? }
                         ) #post_transform
                     }
+
+                    fn from_attributes_with_rest(
+                        #input: &[_darling::export::syn::Attribute],
+                    ) -> _darling::Result<(Self, _darling::export::Vec<_darling::export::syn::Attribute>)> {
+                        let (__inner, __rest) = _darling::FromAttributes::from_attributes_with_rest(#input)?;
+                        _darling::export::Ok((#ty_ident { #member: __inner }, __rest))
+                    }
                 },
                 tokens,
             );
@@ -49,6 +56,16 @@ impl ToTokens for FromAttributesImpl<'_> {
 
         let grab_attrs = self.extractor();
 
+        let attr_names = self.attr_names.to_strings();
+        let is_parsed = if attr_names.is_empty() {
+            quote!(false)
+        } else {
+            quote!(matches!(
+                _darling::util::path_to_string(__attr.path()).as_str(),
+                #(#attr_names)|*
+            ))
+        };
+
         let declare_errors = self.base.declare_errors();
         let require_fields = self.base.require_fields();
         let check_errors = self.base.check_errors();
@@ -71,6 +88,17 @@ impl ToTokens for FromAttributesImpl<'_> {
                         #inits
                     }) #post_transform
                 }
+
+                fn from_attributes_with_rest(
+                    #input: &[_darling::export::syn::Attribute],
+                ) -> _darling::Result<(Self, _darling::export::Vec<_darling::export::syn::Attribute>)> {
+                    let __rest = #input
+                        .iter()
+                        .filter(|__attr| !#is_parsed)
+                        .cloned()
+                        .collect();
+                    <Self as _darling::FromAttributes>::from_attributes(#input).map(|__value| (__value, __rest))
+                }
             },
             tokens,
         );
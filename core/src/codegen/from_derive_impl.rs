@@ -20,6 +20,8 @@ pub struct FromDeriveInputImpl<'a> {
     pub base: TraitImpl<'a>,
     pub attr_names: &'a PathList,
     pub forward_attrs: ForwardAttrs<'a>,
+    /// If set, the field which should receive the span of the first matched attribute.
+    pub attr_span: Option<&'a Ident>,
     pub from_ident: bool,
     pub supports: Option<&'a DeriveInputShapeSet>,
 }
@@ -54,8 +56,9 @@ impl ToTokens for FromDeriveInputImpl<'_> {
             self.ident.as_ref().map(|i| ident_field::create(i, &input)),
             self.vis
                 .as_ref()
-                .map(|i| parse_quote!(#i: #input.vis.clone())),
+                .map(|i| parse_quote!(#i: _darling::export::Into::into(#input.vis.clone()))),
             self.forward_attrs.to_field_value(),
+            self.attr_span_field_value(),
             self.generics.map(|g| g.to_field_value()),
             self.data.map(|f| f.to_field_value()),
         ]
@@ -159,6 +162,10 @@ impl ExtractAttribute for FromDeriveInputImpl<'_> {
         &self.forward_attrs
     }
 
+    fn attr_span(&self) -> Option<&Ident> {
+        self.attr_span
+    }
+
     fn param_name(&self) -> TokenStream {
         quote!(__di)
     }
@@ -16,9 +16,13 @@ pub struct FromFieldImpl<'a> {
     pub ident: Option<&'a ForwardedField>,
     pub vis: Option<&'a Ident>,
     pub ty: Option<&'a Ident>,
+    /// If set, the field which receives the position of the input field within its parent.
+    pub index: Option<&'a Ident>,
     pub base: TraitImpl<'a>,
     pub attr_names: &'a PathList,
     pub forward_attrs: ForwardAttrs<'a>,
+    /// If set, the field which should receive the span of the first matched attribute.
+    pub attr_span: Option<&'a Ident>,
     pub from_ident: bool,
 }
 
@@ -44,11 +48,13 @@ impl ToTokens for FromFieldImpl<'_> {
                 .map(|i| ident_field::create_optional(i, &input)),
             self.vis
                 .as_ref()
-                .map(|i| parse_quote!(#i: #input.vis.clone())),
+                .map(|i| parse_quote!(#i: _darling::export::Into::into(#input.vis.clone()))),
             self.ty
                 .as_ref()
                 .map(|i| parse_quote!(#i: #input.ty.clone())),
+            self.index.as_ref().map(|i| parse_quote!(#i: __index)),
             self.forward_attrs.to_field_value(),
+            self.attr_span_field_value(),
         ]
         .into_iter()
         .flatten();
@@ -57,28 +63,46 @@ impl ToTokens for FromFieldImpl<'_> {
         let grab_attrs = self.extractor();
         let post_transform = self.base.post_transform_call();
 
-        self.wrap(
-            quote! {
-                fn from_field(#input: &_darling::export::syn::Field) -> _darling::Result<Self> {
-                    #error_declaration
+        let body = quote! {
+            #error_declaration
 
-                    #grab_attrs
+            #grab_attrs
 
-                    #require_fields
+            #require_fields
 
-                    #error_check
+            #error_check
 
-                    #default
+            #default
+
+            _darling::export::Ok(Self {
+                #(#forwarded_fields,)*
+                #initializers
+            }) #post_transform
+        };
 
-                    _darling::export::Ok(Self {
-                        #(#forwarded_fields,)*
-                        #initializers
-                    }) #post_transform
+        // The body needs the field's index when it is forwarded, so it is generated for both
+        // `from_field`, where the index is unknown, and `from_field_at`.
+        let methods = if self.index.is_some() {
+            quote! {
+                fn from_field(#input: &_darling::export::syn::Field) -> _darling::Result<Self> {
+                    let __index = _darling::export::None;
+                    #body
+                }
 
+                fn from_field_at(#input: &_darling::export::syn::Field, __index: usize) -> _darling::Result<Self> {
+                    let __index = _darling::export::Some(__index);
+                    #body
                 }
-            },
-            tokens,
-        );
+            }
+        } else {
+            quote! {
+                fn from_field(#input: &_darling::export::syn::Field) -> _darling::Result<Self> {
+                    #body
+                }
+            }
+        };
+
+        self.wrap(methods, tokens);
     }
 }
 
@@ -91,6 +115,10 @@ impl ExtractAttribute for FromFieldImpl<'_> {
         &self.forward_attrs
     }
 
+    fn attr_span(&self) -> Option<&Ident> {
+        self.attr_span
+    }
+
     fn param_name(&self) -> TokenStream {
         quote!(__field)
     }
@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use syn::spanned::Spanned;
 
@@ -13,6 +13,9 @@ pub struct FromMetaImpl<'a> {
     pub from_word: Option<Cow<'a, Callable>>,
     pub from_none: Option<&'a Callable>,
     pub from_expr: Option<&'a Callable>,
+    pub from_string: Option<&'a Callable>,
+    /// The name of the item whose value selects the variant of an internally-tagged enum.
+    pub tag: Option<&'a str>,
     pub derive_syn_parse: bool,
 }
 
@@ -44,19 +47,76 @@ impl ToTokens for FromMetaImpl<'_> {
             }
         });
 
+        let from_string = self.from_string.map(|body| {
+            quote_spanned! {body.span()=>
+                fn from_string(value: &str) -> _darling::Result<Self> {
+                    _darling::export::identity::<fn(&str) -> _darling::Result<Self>>(#body)(value)
+                }
+            }
+        });
+
+        // A list holding a single string literal, e.g. `#[size("100x200")]`, is treated
+        // the same as `size = "100x200"` when the struct has a `from_string` override.
+        let list_string_shorthand = self.from_string.map(|_| {
+            quote! {
+                if let [_darling::export::NestedMeta::Lit(_darling::export::syn::Lit::Str(ref __lit))] = *__items {
+                    return <Self as _darling::FromMeta>::from_string(&__lit.value())
+                        .map_err(|e| e.with_span(__lit));
+                }
+            }
+        });
+
         // Transparent proxies to the sole value it contains
-        if let Some((member, _)) = base
+        if let Some((member, field)) = base
             .data
             .as_struct()
             .and_then(|fields| super::extract_transparent(fields, base.transparent))
         {
             let ty_ident = base.ident;
+            let ty = field.ty;
             let impl_block = quote!(
-                fn from_meta(__item: &_darling::export::syn::Meta) -> _darling::Result<Self> {
-                    _darling::FromMeta::from_meta(__item)
+                fn from_nested_meta(__item: &_darling::export::NestedMeta) -> _darling::Result<Self> {
+                    _darling::FromMeta::from_nested_meta(__item)
                         .map_err(|e| e.with_span(&__item))
                         .map(|value| #ty_ident { #member: value })
                 }
+
+                fn from_meta(__item: &_darling::export::syn::Meta) -> _darling::Result<Self> {
+                    match (<#ty as _darling::FromMeta>::from_meta(__item), __item) {
+                        // The inner type doesn't take lists, so fall back to the one-value
+                        // shorthand in `from_list`.
+                        (_darling::export::Err(ref __error), _darling::export::syn::Meta::List(ref __list))
+                            if __error.code() == _darling::export::Some("darling::unexpected_format") =>
+                        {
+                            <Self as _darling::FromMeta>::from_list(
+                                &_darling::export::NestedMeta::parse_meta_list(__list.tokens.clone())?,
+                            )
+                            .map_err(|e| e.with_span(&__item))
+                        }
+                        (__result, _) => __result
+                            .map_err(|e| e.with_span(&__item))
+                            .map(|value| #ty_ident { #member: value }),
+                    }
+                }
+
+                // Lists go to the inner type first. If it doesn't take lists, a list holding
+                // one value, e.g. the arguments of `#[port(8080)]`, is that value.
+                fn from_list(__items: &[_darling::export::NestedMeta]) -> _darling::Result<Self> {
+                    match <#ty as _darling::FromMeta>::from_list(__items) {
+                        _darling::export::Err(ref __error)
+                            if __error.code() == _darling::export::Some("darling::unexpected_format") =>
+                        {
+                            match *__items {
+                                [ref __item] => <Self as _darling::FromMeta>::from_nested_meta(__item),
+                                [] => _darling::export::Err(_darling::Error::too_few_items(1)),
+                                [_, ref __extra, ..] => _darling::export::Err(
+                                    _darling::Error::too_many_items(1).with_span(__extra),
+                                ),
+                            }
+                        }
+                        __result => __result.map(|value| #ty_ident { #member: value }),
+                    }
+                }
             );
 
             self.wrap(impl_block, tokens);
@@ -74,13 +134,95 @@ impl ToTokens for FromMetaImpl<'_> {
                     fn from_word() -> _darling::Result<Self> {
                         _darling::export::Ok(#ty_ident)
                     }
+
+                    #from_string
                 )
             }
+            // Tuple structs read their fields positionally, e.g. `#[range(0, 100)]`.
             Data::Struct(Fields {
                 style: Style::Tuple,
+                ref fields,
                 ..
             }) => {
-                panic!("Multi-field tuples are not supported");
+                let len = fields.len();
+                let idents: Vec<_> = (0..len)
+                    .map(|i| syn::Ident::new(&format!("__field_{}", i), Span::call_site()))
+                    .collect();
+                let parses = fields.iter().enumerate().map(|(i, field)| {
+                    let post_transform = field.post_transform;
+                    quote!(
+                        __errors.handle(
+                            _darling::FromMeta::from_nested_meta(&__items[#i])
+                                #(#post_transform)*
+                                .map_err(|e| e.with_span(&__items[#i]))
+                        )
+                    )
+                });
+                let nones: Vec<_> = idents
+                    .iter()
+                    .map(|_| quote!(_darling::export::None))
+                    .collect();
+                let declare_errors = base.declare_errors();
+                let check_errors = base.check_errors();
+                let post_transform = base.post_transform_call();
+
+                quote!(
+                    #from_word
+
+                    #from_none
+
+                    #from_expr
+
+                    #from_string
+
+                    fn from_list(__items: &[_darling::export::NestedMeta]) -> _darling::Result<Self> {
+                        #list_string_shorthand
+
+                        #declare_errors
+
+                        let mut __named = false;
+                        for __item in __items {
+                            let __path = match *__item {
+                                _darling::export::NestedMeta::Meta(_darling::export::syn::Meta::NameValue(ref __nv)) => &__nv.path,
+                                _darling::export::NestedMeta::NameValueInvalidExpr(ref __nv) => &__nv.path,
+                                _ => continue,
+                            };
+                            __named = true;
+                            __errors.push(
+                                _darling::Error::custom(format!(
+                                    "expected a positional value, found named item `{}`",
+                                    _darling::util::path_to_string(__path)
+                                ))
+                                .with_span(__item),
+                            );
+                        }
+
+                        // Named items were already reported, so don't also read them as values.
+                        let (#(#idents,)*) = if __named {
+                            (#(#nones,)*)
+                        } else if __items.len() == #len {
+                            (#(#parses,)*)
+                        } else if __items.len() < #len {
+                            let __error = _darling::Error::too_few_items(#len);
+                            __errors.push(match __items.last() {
+                                _darling::export::Some(__last) => __error.with_span(__last),
+                                _darling::export::None => __error,
+                            });
+                            (#(#nones,)*)
+                        } else {
+                            __errors.push(
+                                _darling::Error::too_many_items(#len).with_span(&__items[#len]),
+                            );
+                            (#(#nones,)*)
+                        };
+
+                        #check_errors
+
+                        _darling::export::Ok(Self(
+                            #(#idents.expect("errors were checked above")),*
+                        )) #post_transform
+                    }
+                )
             }
             Data::Struct(ref data) => {
                 let inits = data.fields.iter().map(Field::as_initializer);
@@ -99,7 +241,10 @@ impl ToTokens for FromMetaImpl<'_> {
 
                     #from_expr
 
+                    #from_string
+
                     fn from_list(__items: &[_darling::export::NestedMeta]) -> _darling::Result<Self> {
+                        #list_string_shorthand
 
                         #decls
 
@@ -122,8 +267,13 @@ impl ToTokens for FromMetaImpl<'_> {
             Data::Enum(ref variants) => {
                 let unit_arms = variants.iter().map(Variant::as_unit_match_arm);
 
-                let (unknown_variant_err, unknown_unit_variant_err) = if !variants.is_empty() {
-                    let names = variants.iter().map(Variant::as_name);
+                // The `other` variant isn't matched by name, so it's not a valid alternative.
+                let names: Vec<_> = variants
+                    .iter()
+                    .filter(|v| !v.other)
+                    .map(Variant::as_name)
+                    .collect();
+                let (unknown_variant_err, unknown_unit_variant_err) = if !names.is_empty() {
                     let names = quote!(&[#(#names),*]);
                     (
                         quote! {
@@ -144,30 +294,89 @@ impl ToTokens for FromMetaImpl<'_> {
 
                 let data_variants = variants.iter().map(Variant::as_data_match_arm);
 
-                quote!(
-                    fn from_list(__outer: &[_darling::export::NestedMeta]) -> _darling::Result<Self> {
-                        // An enum must have exactly one value inside the parentheses if it's not a unit
-                        // match arm.
-                        match __outer.len() {
-                            0 => _darling::export::Err(_darling::Error::too_few_items(1)),
-                            1 => {
-                                if let _darling::export::NestedMeta::Meta(ref __nested) = __outer[0] {
-                                    match _darling::util::path_to_string(__nested.path()).as_ref() {
-                                        #(#data_variants)*
-                                        __other => _darling::export::Err(_darling::Error::#unknown_variant_err.with_span(__nested))
+                let from_list = if let Some(tag) = self.tag {
+                    let tagged_variants = variants.iter().map(Variant::as_tagged_match_arm);
+                    // The tag selects the variant, and all other items are parsed into it.
+                    quote!(
+                        fn from_list(__outer: &[_darling::export::NestedMeta]) -> _darling::Result<Self> {
+                            let mut __tag = _darling::export::None;
+                            let mut __fields = _darling::export::Vec::new();
+
+                            for __item in __outer {
+                                match *__item {
+                                    _darling::export::NestedMeta::Meta(_darling::export::syn::Meta::NameValue(ref __nv))
+                                        if __nv.path.is_ident(#tag) =>
+                                    {
+                                        if __tag.is_some() {
+                                            return _darling::export::Err(_darling::Error::duplicate_field(#tag).with_span(__item));
+                                        }
+
+                                        let __value: _darling::export::String = _darling::FromMeta::from_expr(&__nv.value)
+                                            .map_err(|e| e.at(#tag))?;
+                                        __tag = _darling::export::Some((__value, &__nv.value));
                                     }
-                                } else {
-                                    _darling::export::Err(_darling::Error::unsupported_format("literal"))
+                                    _ => __fields.push(_darling::export::Clone::clone(__item)),
                                 }
                             }
-                            _ => _darling::export::Err(_darling::Error::too_many_items(1)),
+
+                            let (__tag, __tag_value) = match __tag {
+                                _darling::export::Some(__tag) => __tag,
+                                _darling::export::None => {
+                                    return _darling::export::Err(_darling::Error::missing_field(#tag));
+                                }
+                            };
+                            let __items = &__fields[..];
+
+                            match __tag.as_str() {
+                                #(#tagged_variants)*
+                                __other => _darling::export::Err(
+                                    _darling::Error::#unknown_unit_variant_err
+                                        .with_span(__tag_value)
+                                        .at(#tag)
+                                ),
+                            }
                         }
-                    }
+                    )
+                } else {
+                    quote!(
+                        fn from_list(__outer: &[_darling::export::NestedMeta]) -> _darling::Result<Self> {
+                            // An enum must have exactly one value inside the parentheses if it's not a unit
+                            // match arm.
+                            match __outer.len() {
+                                0 => _darling::export::Err(_darling::Error::too_few_items(1)),
+                                1 => {
+                                    if let _darling::export::NestedMeta::Meta(ref __nested) = __outer[0] {
+                                        match _darling::util::path_to_string(__nested.path()).as_ref() {
+                                            #(#data_variants)*
+                                            __other => _darling::export::Err(_darling::Error::#unknown_variant_err.with_span(__nested))
+                                        }
+                                    } else {
+                                        _darling::export::Err(_darling::Error::unsupported_format("literal"))
+                                    }
+                                }
+                                _ => _darling::export::Err(_darling::Error::too_many_items(1)),
+                            }
+                        }
+                    )
+                };
+
+                // Validation ensures at most one variant is marked `other`.
+                let other_arm = variants
+                    .iter()
+                    .find(|v| v.other)
+                    .map(Variant::as_other_match_arm)
+                    .map_or_else(
+                        || quote!(__other => _darling::export::Err(_darling::Error::#unknown_unit_variant_err)),
+                        |arm| quote!(#arm),
+                    );
+
+                quote!(
+                    #from_list
 
                     fn from_string(lit: &str) -> _darling::Result<Self> {
                         match lit {
                             #(#unit_arms)*
-                            __other => _darling::export::Err(_darling::Error::#unknown_unit_variant_err)
+                            #other_arm
                         }
                     }
 
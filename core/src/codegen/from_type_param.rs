@@ -13,6 +13,8 @@ pub struct FromTypeParamImpl<'a> {
     pub default: Option<&'a Ident>,
     pub attr_names: &'a PathList,
     pub forward_attrs: ForwardAttrs<'a>,
+    /// If set, the field which should receive the span of the first matched attribute.
+    pub attr_span: Option<&'a Ident>,
     pub from_ident: bool,
 }
 
@@ -34,6 +36,7 @@ impl ToTokens for FromTypeParamImpl<'_> {
         let forwarded_fields = vec![
             self.ident.as_ref().map(|i| ident_field::create(i, &input)),
             self.forward_attrs.to_field_value(),
+            self.attr_span_field_value(),
             self.bounds
                 .as_ref()
                 .map(|i| parse_quote!(#i: #input.bounds.clone().into_iter().collect::<Vec<_>>())),
@@ -81,6 +84,10 @@ impl ExtractAttribute for FromTypeParamImpl<'_> {
         &self.forward_attrs
     }
 
+    fn attr_span(&self) -> Option<&Ident> {
+        self.attr_span
+    }
+
     fn param_name(&self) -> TokenStream {
         quote!(__type_param)
     }
@@ -28,6 +28,8 @@ pub struct FromVariantImpl<'a> {
     pub discriminant: Option<&'a Ident>,
     pub attr_names: &'a PathList,
     pub forward_attrs: ForwardAttrs<'a>,
+    /// If set, the field which should receive the span of the first matched attribute.
+    pub attr_span: Option<&'a Ident>,
     pub from_ident: bool,
     pub supports: Option<&'a DataShape>,
 }
@@ -43,6 +45,7 @@ impl ToTokens for FromVariantImpl<'_> {
                 |i| parse_quote!(#i: #input.discriminant.as_ref().map(|(_, expr)| expr.clone())),
             ),
             self.forward_attrs.to_field_value(),
+            self.attr_span_field_value(),
             self.fields.as_ref().map(|i| i.to_field_value()),
         ]
         .into_iter()
@@ -132,6 +135,10 @@ impl ExtractAttribute for FromVariantImpl<'_> {
         &self.forward_attrs
     }
 
+    fn attr_span(&self) -> Option<&Ident> {
+        self.attr_span
+    }
+
     fn param_name(&self) -> TokenStream {
         quote!(__variant)
     }
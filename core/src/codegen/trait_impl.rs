@@ -7,6 +7,7 @@ use crate::codegen::{
     error::{ErrorCheck, ErrorDeclaration},
     DefaultExpression, Field, FieldsGen, PostfixTransform, Variant,
 };
+use crate::options::UnknownFields;
 use crate::usage::{CollectTypeParams, IdentSet, Purpose};
 
 #[derive(Debug)]
@@ -16,7 +17,7 @@ pub struct TraitImpl<'a> {
     pub data: Data<Variant<'a>, Field<'a>>,
     pub default: Option<DefaultExpression<'a>>,
     pub post_transform: Option<&'a PostfixTransform>,
-    pub allow_unknown_fields: bool,
+    pub unknown_fields: UnknownFields,
     pub transparent: bool,
     pub krate: Option<&'a syn::Path>,
 }
@@ -120,8 +121,24 @@ impl<'a> TraitImpl<'a> {
                 v.as_flatten_initializer(vd.fields.iter().filter_map(Field::as_name).collect())
             });
 
+            // Likewise, the rest field takes ownership of the unknown meta items it collected.
+            let rest_field_init = vd
+                .fields
+                .iter()
+                .find(|f| f.rest)
+                .map(Field::as_rest_initializer);
+
+            // And the warnings field takes ownership of the warnings recorded along the way.
+            let warnings_field_init = vd
+                .fields
+                .iter()
+                .find(|f| f.warnings)
+                .map(Field::as_warnings_initializer);
+
             quote! {
                 #flatten_field_init
+                #rest_field_init
+                #warnings_field_init
                 #(#checks)*
             }
         } else {
@@ -141,7 +158,7 @@ impl<'a> TraitImpl<'a> {
     fn make_field_ctx(&'a self) -> FieldsGen<'a> {
         match self.data {
             Data::Enum(_) => panic!("Core loop on enums isn't supported"),
-            Data::Struct(ref data) => FieldsGen::new(data, self.allow_unknown_fields),
+            Data::Struct(ref data) => FieldsGen::new(data, self.unknown_fields),
         }
     }
 }
@@ -7,6 +7,7 @@ use syn::Ident;
 use crate::ast::Fields;
 use crate::codegen::error::{ErrorCheck, ErrorDeclaration};
 use crate::codegen::{from_none_call, Field, FieldsGen};
+use crate::options::UnknownFields;
 use crate::usage::{self, IdentRefSet, IdentSet, UsesTypeParams};
 
 /// A variant of the enum which is deriving `FromMeta`.
@@ -26,9 +27,13 @@ pub struct Variant<'a> {
     /// Whether or not the variant should be skipped in the generated code.
     pub skip: bool,
 
-    pub allow_unknown_fields: bool,
+    pub unknown_fields: UnknownFields,
 
     pub transparent: bool,
+
+    /// Whether or not the variant receives unrecognized values in `from_string`.
+    /// Such variants are not matched by name.
+    pub other: bool,
 }
 
 impl<'a> Variant<'a> {
@@ -36,6 +41,12 @@ impl<'a> Variant<'a> {
         &self.name_in_attr
     }
 
+    /// Get the fallback arm for `from_string`, which passes an unrecognized value
+    /// to this variant's field.
+    pub fn as_other_match_arm(&'a self) -> OtherMatchArm<'a> {
+        OtherMatchArm(self)
+    }
+
     pub fn as_unit_match_arm(&'a self) -> UnitMatchArm<'a> {
         UnitMatchArm(self)
     }
@@ -43,6 +54,10 @@ impl<'a> Variant<'a> {
     pub fn as_data_match_arm(&'a self) -> DataMatchArm<'a> {
         DataMatchArm(self)
     }
+
+    pub fn as_tagged_match_arm(&'a self) -> TaggedMatchArm<'a> {
+        TaggedMatchArm(self)
+    }
 }
 
 impl UsesTypeParams for Variant<'_> {
@@ -64,7 +79,7 @@ impl ToTokens for UnitMatchArm<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let val: &Variant<'_> = self.0;
 
-        if val.skip {
+        if val.skip || val.other {
             return;
         }
 
@@ -108,6 +123,21 @@ impl ToTokens for UnitMatchArm<'_> {
     }
 }
 
+/// Code generator for the fallback arm of generated `from_string` calls for an enum with
+/// a `#[darling(other)]` newtype variant.
+pub struct OtherMatchArm<'a>(&'a Variant<'a>);
+
+impl ToTokens for OtherMatchArm<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ty_ident = self.0.ty_ident;
+        let variant_ident = self.0.variant_ident;
+
+        tokens.append_all(quote!(
+            __other => _darling::FromMeta::from_string(__other).map(#ty_ident::#variant_ident)
+        ));
+    }
+}
+
 /// Code generator for an enum variant in a data-carrying match position.
 /// This is placed in generated `from_list` calls for the parent enum.
 /// Unit variants wrapped in this type will emit code to produce an "unsupported format" error.
@@ -117,7 +147,7 @@ impl ToTokens for DataMatchArm<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let val: &Variant<'_> = self.0;
 
-        if val.skip {
+        if val.skip || val.other {
             return;
         }
 
@@ -127,13 +157,16 @@ impl ToTokens for DataMatchArm<'_> {
 
         if val.data.is_unit() {
             // Allow unit variants to match a list item if it's just a path with no associated
-            // value, e.g. `volume(shout)` is allowed.
+            // value, e.g. `volume(shout)` is allowed. An empty list, e.g. `volume(shout())`,
+            // carries no value either, so it's accepted too.
             tokens.append_all(quote!(
                 #name_in_attr => {
-                    if let _darling::export::syn::Meta::Path(_) = *__nested {
-                        _darling::export::Ok(#ty_ident::#variant_ident)
-                    } else {
-                        _darling::export::Err(_darling::Error::unsupported_format("non-path"))
+                    match *__nested {
+                        _darling::export::syn::Meta::Path(_) => _darling::export::Ok(#ty_ident::#variant_ident),
+                        _darling::export::syn::Meta::List(ref __list) if __list.tokens.is_empty() => {
+                            _darling::export::Ok(#ty_ident::#variant_ident)
+                        }
+                        _ => _darling::export::Err(_darling::Error::unsupported_format("non-path")),
                     }
                 },
             ));
@@ -141,7 +174,7 @@ impl ToTokens for DataMatchArm<'_> {
             return;
         }
 
-        let vdg = FieldsGen::new(&val.data, val.allow_unknown_fields);
+        let vdg = FieldsGen::new(&val.data, val.unknown_fields);
 
         if let Some((member, _)) = super::extract_transparent(&val.data, val.transparent) {
             tokens.append_all(quote!(
@@ -191,3 +224,70 @@ impl ToTokens for DataMatchArm<'_> {
         }
     }
 }
+
+/// Code generator for an enum variant in an internally-tagged match position.
+/// This is placed in generated `from_list` calls for a parent enum with `#[darling(tag = "...")]`,
+/// where `__items` holds every item except the tag.
+pub struct TaggedMatchArm<'a>(&'a Variant<'a>);
+
+impl ToTokens for TaggedMatchArm<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let val: &Variant<'_> = self.0;
+
+        if val.skip || val.other {
+            return;
+        }
+
+        let name_in_attr = &val.name_in_attr;
+        let variant_ident = val.variant_ident;
+        let ty_ident = val.ty_ident;
+
+        if val.data.is_unit() {
+            // A unit variant has no fields, so `()` reports each sibling item as unknown.
+            tokens.append_all(quote!(
+                #name_in_attr => {
+                    <() as _darling::FromMeta>::from_list(__items)
+                        .map(|()| #ty_ident::#variant_ident)
+                }
+            ));
+        } else if let Some((member, _)) = super::extract_transparent(&val.data, val.transparent) {
+            tokens.append_all(quote!(
+                #name_in_attr => {
+                    _darling::export::Ok(
+                        #ty_ident::#variant_ident {
+                            #member: _darling::FromMeta::from_list(__items)?
+                        }
+                    )
+                }
+            ));
+        } else if val.data.is_struct() {
+            let vdg = FieldsGen::new(&val.data, val.unknown_fields);
+            let declare_errors = ErrorDeclaration::default();
+            let check_errors = ErrorCheck::default();
+            let require_fields = vdg.require_fields();
+            let decls = vdg.declarations();
+            let core_loop = vdg.core_loop();
+            let inits = vdg.initializers();
+
+            tokens.append_all(quote!(
+                #name_in_attr => {
+                    #declare_errors
+
+                    #decls
+
+                    #core_loop
+
+                    #require_fields
+
+                    #check_errors
+
+                    _darling::export::Ok(#ty_ident::#variant_ident {
+                        #inits
+                    })
+                }
+            ));
+        } else {
+            panic!("Match arms aren't supported for tuple variants yet");
+        }
+    }
+}
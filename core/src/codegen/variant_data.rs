@@ -3,17 +3,18 @@ use quote::quote;
 
 use crate::ast::{Fields, Style};
 use crate::codegen::Field;
+use crate::options::UnknownFields;
 
 pub struct FieldsGen<'a> {
     fields: &'a Fields<Field<'a>>,
-    allow_unknown_fields: bool,
+    unknown_fields: UnknownFields,
 }
 
 impl<'a> FieldsGen<'a> {
-    pub fn new(fields: &'a Fields<Field<'a>>, allow_unknown_fields: bool) -> Self {
+    pub fn new(fields: &'a Fields<Field<'a>>, unknown_fields: UnknownFields) -> Self {
         Self {
             fields,
-            allow_unknown_fields,
+            unknown_fields,
         }
     }
 
@@ -42,11 +43,31 @@ impl<'a> FieldsGen<'a> {
                 __flatten.push(__item.clone());
             }
         }
+        // If there is a rest field, collect the unknown meta item under its name.
+        // Each name may only appear once, since the field is typically a map.
+        else if self.fields.iter().any(|f| f.rest) {
+            quote! {
+                match *__item {
+                    _darling::export::NestedMeta::Meta(ref __inner) => {
+                        if __rest.iter().any(|(__seen, _)| *__seen == __name) {
+                            __errors.push(_darling::Error::duplicate_field(&__name).with_span(__item));
+                        } else {
+                            __rest.push((__name.clone(), __inner.clone()));
+                        }
+                    }
+                    _darling::export::NestedMeta::NameValueInvalidExpr(ref __inner) => {
+                        __errors.push(__inner.error.clone());
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        }
         // If we're allowing unknown fields, then handling one is a no-op.
-        else if self.allow_unknown_fields {
+        else if self.unknown_fields == UnknownFields::Allow {
             quote!()
         }
-        // Otherwise, we're going to push a new spanned error pointing at the field.
+        // Otherwise, we're going to create a new spanned error pointing at the field,
+        // and either record it as a warning or push it to the accumulator.
         else {
             let mut names = self.fields.iter().filter_map(Field::as_name).peekable();
             // We can't call `unknown_field_with_alts` with an empty slice, or else it fails to
@@ -57,23 +78,72 @@ impl<'a> FieldsGen<'a> {
                 quote!(unknown_field_with_alts(__other, &[#(#names),*]))
             };
 
-            quote! {
-                __errors.push(_darling::Error::#err_fn.with_span(__item));
+            if self.unknown_fields == UnknownFields::Warn {
+                quote! {
+                    __warnings.push(_darling::Error::#err_fn.with_span(__item));
+                }
+            } else {
+                quote! {
+                    __errors.push(_darling::Error::#err_fn.with_span(__item));
+                }
             }
         };
         let arms = arms.iter();
 
+        // Positional fields consume the leading items before any names are matched.
+        // Any literal left over is then either surplus or out of order.
+        let positionals: Vec<_> = self
+            .fields
+            .iter()
+            .filter(|f| f.positional)
+            .map(Field::as_positional)
+            .collect();
+        let (read_positionals, unexpected_lit, mark_named) = if positionals.is_empty() {
+            (
+                quote!(),
+                quote!(_darling::Error::unsupported_format("literal").with_span(__inner)),
+                quote!(),
+            )
+        } else {
+            let count = positionals.len();
+            (
+                quote! {
+                    let __items = {
+                        let mut __positional = __items;
+                        #(#positionals)*
+                        __positional
+                    };
+                    let mut __seen_named = false;
+                },
+                quote! {
+                    if __seen_named {
+                        _darling::Error::custom("positional values must come before named items")
+                    } else {
+                        _darling::Error::custom(format!(
+                            "expected at most {} positional value{}",
+                            #count,
+                            if #count == 1 { "" } else { "s" }
+                        ))
+                    }
+                    .with_span(__inner)
+                },
+                quote!(__seen_named = true;),
+            )
+        };
+
         quote!(
+            #read_positionals
+
             for __item in __items {
                 let __path = match *__item {
                     _darling::export::NestedMeta::Meta(ref __inner) => __inner.path(),
                     _darling::export::NestedMeta::NameValueInvalidExpr(ref __inner) => &__inner.path,
                     _darling::export::NestedMeta::Lit(ref __inner) => {
-                        __errors.push(_darling::Error::unsupported_format("literal")
-                            .with_span(__inner));
+                        __errors.push(#unexpected_lit);
                         continue;
                     }
                 };
+                #mark_named
                 let __name = _darling::util::path_to_string(__path);
 
                 match __name.as_str() {
@@ -91,8 +161,15 @@ impl<'a> FieldsGen<'a> {
                 ref fields,
                 ..
             } => {
+                let warnings_init = fields
+                    .iter()
+                    .find(|f| f.warnings)
+                    .map(Field::as_warnings_initializer);
                 let checks = fields.iter().map(Field::as_presence_check);
-                quote!(#(#checks)*)
+                quote! {
+                    #warnings_init
+                    #(#checks)*
+                }
             }
             _ => panic!("FieldsGen doesn't support tuples for requirement checks"),
         }
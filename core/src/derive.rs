@@ -59,3 +59,107 @@ pub fn from_type_param(input: &DeriveInput) -> TokenStream {
 pub fn from_variant(input: &DeriveInput) -> TokenStream {
     emit_impl_or_error!(options::FromVariantOptions::new(input))
 }
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use syn::parse_quote;
+
+    use super::from_meta;
+
+    fn contains(haystack: &proc_macro2::TokenStream, needle: proc_macro2::TokenStream) -> bool {
+        haystack.to_string().contains(&needle.to_string())
+    }
+
+    #[test]
+    fn default_crate_path() {
+        let output = from_meta(&parse_quote! {
+            struct Options {
+                level: u8,
+            }
+        });
+
+        assert!(contains(
+            &output,
+            quote!(
+                use ::darling as _darling;
+            )
+        ));
+    }
+
+    #[test]
+    fn container_default() {
+        let output = from_meta(&parse_quote! {
+            #[darling(default)]
+            struct Options {
+                level: u8,
+                name: String,
+            }
+        });
+
+        assert!(contains(
+            &output,
+            quote!(let __default: Self = _darling::export::Default::default();)
+        ));
+        assert!(contains(&output, quote!(__default.level)));
+        assert!(contains(&output, quote!(__default.name)));
+    }
+
+    #[test]
+    fn tuple_struct_item_count() {
+        let output = from_meta(&parse_quote! {
+            struct Range(u32, u32);
+        });
+
+        assert!(contains(
+            &output,
+            quote!(_darling::Error::too_few_items(2usize))
+        ));
+        assert!(contains(
+            &output,
+            quote!(_darling::Error::too_many_items(2usize).with_span(&__items[2usize]))
+        ));
+    }
+
+    #[test]
+    fn tuple_struct_rejects_field_options() {
+        let output = from_meta(&parse_quote! {
+            struct Range(#[darling(with = parse_start)] u32, #[darling(default)] u32);
+        });
+
+        let output = output.to_string();
+        assert!(output.contains("`with` is not supported on tuple struct fields"));
+        assert!(output.contains("`default` is not supported on tuple struct fields"));
+    }
+
+    #[test]
+    fn custom_crate_path() {
+        for input in [
+            parse_quote! {
+                #[darling(crate = ::my_facade::darling)]
+                struct Options {
+                    level: u8,
+                }
+            },
+            parse_quote! {
+                #[darling(crate = "::my_facade::darling")]
+                struct Options {
+                    level: u8,
+                }
+            },
+        ] {
+            let output = from_meta(&input);
+            assert!(contains(
+                &output,
+                quote!(
+                    use ::my_facade::darling as _darling;
+                )
+            ));
+            // Every other reference goes through the `_darling` alias.
+            let rest = output
+                .to_string()
+                .replace(&quote!(::my_facade::darling).to_string(), "");
+            assert!(!rest.contains(&quote!(::darling).to_string()));
+        }
+    }
+}
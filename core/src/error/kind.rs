@@ -37,6 +37,24 @@ pub(in crate::error) enum ErrorKind {
 }
 
 impl ErrorKind {
+    /// The stable, machine-readable code for built-in kinds of error.
+    pub fn code(&self) -> Option<&'static str> {
+        use self::ErrorKind::*;
+
+        match *self {
+            Custom(_) | Multiple(_) | __NonExhaustive => None,
+            DuplicateField(_) => Some("darling::duplicate_field"),
+            MissingField(_) => Some("darling::missing_field"),
+            UnsupportedShape { .. } => Some("darling::unsupported_shape"),
+            UnknownField(_) => Some("darling::unknown_field"),
+            UnexpectedFormat(_) => Some("darling::unexpected_format"),
+            UnexpectedType(_) => Some("darling::unexpected_type"),
+            UnknownValue(_) => Some("darling::unknown_value"),
+            TooFewItems(_) => Some("darling::too_few_items"),
+            TooManyItems(_) => Some("darling::too_many_items"),
+        }
+    }
+
     /// Deeply counts the number of errors this item represents.
     pub fn len(&self) -> usize {
         if let ErrorKind::Multiple(ref items) = *self {
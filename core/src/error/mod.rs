@@ -65,6 +65,8 @@ pub struct Error {
     locations: Vec<String>,
     /// The span to highlight in the emitted diagnostic.
     span: Option<Span>,
+    /// A stable, machine-readable identifier for the kind of error.
+    code: Option<&'static str>,
     /// Additional diagnostic messages to show with the error.
     #[cfg(feature = "diagnostics")]
     children: Vec<child::ChildDiagnostic>,
@@ -74,6 +76,7 @@ pub struct Error {
 impl Error {
     pub(in crate::error) fn new(kind: ErrorKind) -> Self {
         Error {
+            code: kind.code(),
             kind,
             locations: Vec::new(),
             span: None,
@@ -340,6 +343,18 @@ impl Error {
     /// * `FromMeta::from_meta`
     /// * `FromMeta::from_nested_meta`
     /// * `FromMeta::from_value`
+    ///
+    /// A bare [`proc_macro2::Span`] is also accepted, which is useful when there is no token
+    /// to point at, such as when reporting a missing field.
+    ///
+    /// ```rust
+    /// # extern crate darling_core as darling;
+    /// # extern crate proc_macro2;
+    /// # use darling::Error;
+    /// let span = proc_macro2::Span::call_site();
+    /// let error = Error::missing_field("name").with_span(&span);
+    /// assert!(error.has_span());
+    /// ```
     pub fn with_span<T: Spanned>(mut self, node: &T) -> Self {
         if !self.has_span() {
             self.span = Some(node.span());
@@ -362,6 +377,88 @@ impl Error {
         self.span
     }
 
+    /// Set a machine-readable code for the error, replacing any existing code.
+    ///
+    /// Tooling which post-processes errors can use this to identify an error without
+    /// matching on its message. Codes should be namespaced by crate, e.g. `"my_crate::bad_name"`.
+    ///
+    /// ```rust
+    /// # extern crate darling_core as darling;
+    /// # use darling::Error;
+    /// let error = Error::custom("Name must be lowercase").with_code("my_crate::bad_name");
+    /// assert_eq!(error.code(), Some("my_crate::bad_name"));
+    /// ```
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Get the machine-readable code for the error, if one has been set.
+    ///
+    /// Errors created by darling's built-in constructors, such as [`Error::unknown_field`],
+    /// carry a stable code of the form `"darling::unknown_field"`. Custom errors and
+    /// collections of multiple errors have no code unless one is set with [`Error::with_code`].
+    pub fn code(&self) -> Option<&'static str> {
+        self.code
+    }
+
+    /// Serialize the error tree as JSON for tooling such as IDE integrations.
+    ///
+    /// Each error is an object with its `message`, `locations`, `code`, and `span`, plus a
+    /// `children` array which holds the nested errors of a multi-error. The span has
+    /// 1-based `line` and 0-based `column` values for its `start` and `end`, and is `null`
+    /// when the error has no span or the location isn't available.
+    ///
+    /// Requires the `json` feature. Span positions also require the `json-span-locations`
+    /// feature, which enables `proc-macro2/span-locations` for the whole build; without it,
+    /// every `span` is `null`.
+    ///
+    /// ```rust
+    /// # extern crate darling_core as darling;
+    /// # use darling::Error;
+    /// let json = Error::missing_field("name").to_json();
+    /// assert!(json.contains(r#""code":"darling::missing_field""#));
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> String {
+        self.to_json_value().to_string()
+    }
+
+    #[cfg(feature = "json")]
+    fn to_json_value(&self) -> serde_json::Value {
+        #[cfg(feature = "json-span-locations")]
+        let span = {
+            fn position(pos: proc_macro2::LineColumn) -> serde_json::Value {
+                serde_json::json!({ "line": pos.line, "column": pos.column })
+            }
+
+            // A line of 0 means the location isn't known, e.g. for `Span::call_site()` outside
+            // of a proc macro.
+            self.span.filter(|span| span.start().line > 0).map(|span| {
+                serde_json::json!({
+                    "start": position(span.start()),
+                    "end": position(span.end()),
+                })
+            })
+        };
+
+        #[cfg(not(feature = "json-span-locations"))]
+        let span: Option<serde_json::Value> = None;
+
+        let children: Vec<_> = match self.kind {
+            ErrorKind::Multiple(ref items) => items.iter().map(Error::to_json_value).collect(),
+            _ => Vec::new(),
+        };
+
+        serde_json::json!({
+            "message": self.kind.to_string(),
+            "locations": self.locations,
+            "code": self.code,
+            "span": span,
+            "children": children,
+        })
+    }
+
     /// Recursively converts a tree of errors to a flattened list.
     ///
     /// # Child Diagnostics
@@ -400,6 +497,10 @@ impl Error {
 
     /// Adds a location to the error, such as a field or variant.
     /// Locations must be added in reverse order of specificity.
+    ///
+    /// Derived impls already call this for each field they parse, so an error from a
+    /// nested struct is reported with its full path, e.g. `at client/retry`. Manual
+    /// impls only need to call it for locations they introduce themselves.
     pub fn at<T: fmt::Display>(mut self, location: T) -> Self {
         self.locations.insert(0, location.to_string());
         self
@@ -412,6 +513,25 @@ impl Error {
         self.at(path_to_string(path))
     }
 
+    /// Appends `other` to this error, so that both are reported together.
+    ///
+    /// This is useful for merging errors from separate analysis into one that darling
+    /// produced before calling [`Self::write_errors`]. Each error keeps its own span and
+    /// locations.
+    ///
+    /// ```rust
+    /// # extern crate darling_core as darling;
+    /// # use darling::Error;
+    /// let mut error = Error::missing_field("name");
+    /// error.add(Error::custom("`version` must be a semver string"));
+    /// assert_eq!(error.len(), 2);
+    /// ```
+    pub fn add(&mut self, other: Error) {
+        let mut errors = std::mem::replace(self, Error::custom("")).into_vec();
+        errors.extend(other.into_vec());
+        *self = Error::multiple(errors);
+    }
+
     /// Gets the number of individual errors in this error.
     ///
     /// This function never returns `0`, as it's impossible to construct
@@ -420,6 +540,30 @@ impl Error {
         self.kind.len()
     }
 
+    /// Iterates over the individual errors contained in `self`, descending into nested
+    /// multi-errors so that only leaf errors are yielded.
+    ///
+    /// The yielded errors keep their own spans and messages, but do not include locations
+    /// added to their ancestors with [`Self::at`]. Use [`Self::flatten`] and then iterate
+    /// by value to get errors with their full location paths.
+    ///
+    /// ```rust
+    /// # extern crate darling_core as darling;
+    /// # use darling::Error;
+    /// let error = Error::multiple(vec![
+    ///     Error::custom("first"),
+    ///     Error::multiple(vec![Error::custom("second"), Error::custom("third")]),
+    /// ]);
+    ///
+    /// let messages = error.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(messages, vec!["first", "second", "third"]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            stack: vec![std::slice::from_ref(self).iter()],
+        }
+    }
+
     /// Consider additional field names as "did you mean" suggestions for
     /// unknown field errors **if and only if** the caller appears to be operating
     /// at error's origin (meaning no calls to [`Self::at`] have yet taken place).
@@ -537,6 +681,30 @@ impl Error {
         }
     }
 
+    /// Write this error and any children as compile errors, adding the field path to the
+    /// message of each error which has no span.
+    ///
+    /// An error without a span is reported at the macro call site, so the path, e.g.
+    /// `at options/name`, is what tells the user which input it came from. Errors with a
+    /// span keep the plain message, since the span already points at the bad input.
+    /// Unlike [`Error::write_errors`], this never emits diagnostics directly, even with the
+    /// `diagnostics` feature enabled, so the output is the same in every build.
+    pub fn write_errors_with_fallback(self) -> TokenStream {
+        let mut syn_errors = self.flatten().into_iter().map(|e| match e.explicit_span() {
+            Some(span) => syn::Error::new(span, e.kind),
+            None => syn::Error::new(e.span(), e),
+        });
+        let mut error = syn_errors
+            .next()
+            .expect("darling::Error can never be empty");
+
+        for next_error in syn_errors {
+            error.combine(next_error);
+        }
+
+        error.into_compile_error()
+    }
+
     #[cfg(feature = "diagnostics")]
     fn single_to_diagnostic(self) -> ::proc_macro::Diagnostic {
         use proc_macro::{Diagnostic, Level};
@@ -785,6 +953,43 @@ impl Iterator for IntoIter {
     }
 }
 
+/// An iterator over the leaf errors in an `Error`, created by [`Error::iter`].
+pub struct Iter<'a> {
+    stack: Vec<std::slice::Iter<'a, Error>>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a Error;
+
+    fn next(&mut self) -> Option<&'a Error> {
+        while let Some(current) = self.stack.last_mut() {
+            match current.next() {
+                Some(error) => {
+                    if let ErrorKind::Multiple(ref errors) = error.kind {
+                        self.stack.push(errors.iter());
+                    } else {
+                        return Some(error);
+                    }
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a> IntoIterator for &'a Error {
+    type Item = &'a Error;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
 /// Accumulator for errors, for helping call [`Error::multiple`].
 ///
 /// See the docs for [`darling::Error`](Error) for more discussion of error handling with darling.
@@ -883,6 +1088,44 @@ impl Accumulator {
         self.errors().push(error)
     }
 
+    /// Mark the current position in the collection, so errors pushed after this point
+    /// can later be discarded using [`Accumulator::rollback`].
+    ///
+    /// This enables speculative parsing: try one approach, and if it fails, roll back
+    /// its errors before trying another.
+    ///
+    /// # Example
+    /// ```rust
+    /// # extern crate darling_core as darling;
+    /// # use darling::{Error, FromMeta};
+    /// # let meta: syn::Meta = syn::parse_quote!(value = "10");
+    /// let mut errors = Error::accumulator();
+    ///
+    /// let savepoint = errors.savepoint();
+    /// let value = match errors.handle(bool::from_meta(&meta)) {
+    ///     Some(flag) => u32::from(flag),
+    ///     None => {
+    ///         errors.rollback(savepoint);
+    ///         errors.handle(u32::from_meta(&meta)).unwrap_or_default()
+    ///     }
+    /// };
+    ///
+    /// assert_eq!(errors.finish_with(value).unwrap(), 10);
+    /// ```
+    pub fn savepoint(&self) -> Savepoint {
+        match &self.0 {
+            Some(errors) => Savepoint(errors.len()),
+            None => panic!("darling internal error: Accumulator accessed after defuse"),
+        }
+    }
+
+    /// Discard all errors pushed since `savepoint` was created.
+    ///
+    /// Errors pushed before the savepoint are kept.
+    pub fn rollback(&mut self, savepoint: Savepoint) {
+        self.errors().truncate(savepoint.0)
+    }
+
     /// Finish the current accumulation, and if there are no errors create a new `Self` so processing may continue.
     ///
     /// This is shorthand for:
@@ -930,6 +1173,11 @@ impl Accumulator {
     }
 }
 
+/// A position in an [`Accumulator`], created by [`Accumulator::savepoint`] and consumed
+/// by [`Accumulator::rollback`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Savepoint(usize);
+
 impl Default for Accumulator {
     fn default() -> Self {
         Accumulator(Some(vec![]))
@@ -995,6 +1243,83 @@ mod tests {
         assert!(err_iter.next().is_none());
     }
 
+    #[test]
+    fn with_bare_span() {
+        let span = proc_macro2::Span::mixed_site();
+        let err = Error::missing_field("hello").with_span(&span);
+        assert!(err.has_span());
+        assert!(err.explicit_span().is_some());
+
+        // Adding another span keeps the error spanned and intact
+        let err = err.with_span(&proc_macro2::Span::call_site());
+        assert!(err.has_span());
+        assert_eq!(err.len(), 1);
+
+        let tokens = syn::Error::from(err).to_compile_error().to_string();
+        assert!(tokens.contains("compile_error"));
+        assert!(tokens.contains("Missing field `hello`"));
+    }
+
+    #[test]
+    fn add_single() {
+        let mut err = Error::duplicate_field("hello");
+        err.add(Error::missing_field("world").at("outer"));
+
+        assert_eq!(2, err.len());
+        let errors: Vec<_> = err.into_iter().collect();
+        assert_eq!(errors[0], Error::duplicate_field("hello"));
+        assert_eq!(errors[1].location(), vec!["outer"]);
+    }
+
+    #[test]
+    fn add_multiple_keeps_locations() {
+        let mut err = Error::multiple(vec![Error::duplicate_field("a"), Error::missing_field("b")])
+            .at("parent");
+        err.add(Error::multiple(vec![
+            Error::custom("c"),
+            Error::custom("d"),
+        ]));
+
+        assert_eq!(4, err.len());
+        let locations: Vec<_> = err.into_iter().map(|e| e.location().len()).collect();
+        assert_eq!(locations, vec![1, 1, 0, 0]);
+    }
+
+    #[test]
+    fn add_writes_all_errors() {
+        let mut err = Error::custom("from darling");
+        err.add(Error::custom("from analysis"));
+        err.add(Error::custom("also from analysis"));
+
+        let tokens = err.write_errors().to_string();
+        assert_eq!(tokens.matches("compile_error").count(), 3);
+        assert!(tokens.contains("from darling"));
+        assert!(tokens.contains("from analysis"));
+        assert!(tokens.contains("also from analysis"));
+    }
+
+    #[test]
+    fn write_errors_fallback_includes_path() {
+        let err = || {
+            let level: syn::Ident = syn::parse_quote!(loud);
+            Error::multiple(vec![
+                Error::unknown_value("loud").with_span(&level).at("level"),
+                Error::custom("bad name").at("name"),
+            ])
+            .at("options")
+        };
+
+        let tokens = err().write_errors_with_fallback().to_string();
+        assert_eq!(tokens.matches("compile_error").count(), 2);
+        assert!(tokens.contains(r#""Unknown value: `loud`""#));
+        assert!(!tokens.contains("at options/level"));
+        assert!(tokens.contains(r#""bad name at options/name""#));
+
+        let err = Error::custom("bad name").at("name");
+        let tokens = err.write_errors_with_fallback().to_string();
+        assert!(tokens.contains(r#""bad name at name""#));
+    }
+
     #[test]
     fn len_single() {
         let err = Error::duplicate_field("hello");
@@ -1024,6 +1349,117 @@ mod tests {
         assert_eq!(4, err.len());
     }
 
+    #[test]
+    fn iter_nested() {
+        let err = Error::multiple(vec![
+            Error::duplicate_field("hello"),
+            Error::multiple(vec![
+                Error::duplicate_field("hi"),
+                Error::missing_field("bye"),
+                Error::multiple(vec![Error::duplicate_field("whatsup")]),
+            ]),
+        ]);
+
+        let messages = err.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        assert_eq!(messages.len(), err.len());
+        assert_eq!(
+            messages,
+            vec![
+                "Duplicate field `hello`",
+                "Duplicate field `hi`",
+                "Missing field `bye`",
+                "Duplicate field `whatsup`",
+            ]
+        );
+    }
+
+    #[test]
+    fn iter_single() {
+        let err = Error::custom("only").with_span(&proc_macro2::Span::call_site());
+        let leaves = (&err).into_iter().collect::<Vec<_>>();
+        assert_eq!(leaves.len(), 1);
+        assert!(leaves[0].has_span());
+    }
+
+    #[test]
+    fn builtin_codes() {
+        assert_eq!(
+            Error::unknown_field("foo").code(),
+            Some("darling::unknown_field")
+        );
+        assert_eq!(
+            Error::unknown_field_with_alts("foo", &["food"]).code(),
+            Some("darling::unknown_field")
+        );
+        assert_eq!(
+            Error::missing_field("foo").code(),
+            Some("darling::missing_field")
+        );
+        assert_eq!(
+            Error::duplicate_field("foo").code(),
+            Some("darling::duplicate_field")
+        );
+        assert_eq!(
+            Error::unknown_value("foo").code(),
+            Some("darling::unknown_value")
+        );
+        assert_eq!(Error::custom("foo").code(), None);
+        assert_eq!(
+            Error::multiple(vec![Error::missing_field("a"), Error::missing_field("b")]).code(),
+            None
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_multi_error_tree() {
+        let span: syn::LitStr = syn::parse_str(r#""bad""#).unwrap();
+        let err = Error::multiple(vec![
+            Error::missing_field("name").at("package"),
+            Error::custom("bad value").with_span(&span).at("version"),
+        ]);
+
+        let json: serde_json::Value = serde_json::from_str(&err.to_json()).unwrap();
+        let children = json["children"].as_array().unwrap();
+        assert_eq!(children.len(), 2);
+
+        assert_eq!(children[0]["message"], "Missing field `name`");
+        assert_eq!(children[0]["locations"], serde_json::json!(["package"]));
+        assert_eq!(children[0]["code"], "darling::missing_field");
+        assert!(children[0]["span"].is_null());
+        assert_eq!(children[0]["children"], serde_json::json!([]));
+
+        assert_eq!(children[1]["message"], "bad value");
+        assert!(children[1]["code"].is_null());
+        #[cfg(feature = "json-span-locations")]
+        assert_eq!(
+            children[1]["span"],
+            serde_json::json!({
+                "start": { "line": 1, "column": 0 },
+                "end": { "line": 1, "column": 5 },
+            })
+        );
+        #[cfg(not(feature = "json-span-locations"))]
+        assert!(children[1]["span"].is_null());
+    }
+
+    #[test]
+    fn code_survives_span_and_location() {
+        let err = Error::missing_field("foo")
+            .with_span(&proc_macro2::Span::call_site())
+            .at("bar");
+        assert_eq!(err.code(), Some("darling::missing_field"));
+    }
+
+    #[test]
+    fn with_code_round_trips() {
+        let err = Error::custom("bad").with_code("my_crate::bad");
+        assert_eq!(err.code(), Some("my_crate::bad"));
+
+        let err = Error::unknown_field("foo").with_code("my_crate::override");
+        assert_eq!(err.code(), Some("my_crate::override"));
+    }
+
     #[test]
     fn accum_ok() {
         let errs = Error::accumulator();
@@ -1059,6 +1495,39 @@ mod tests {
         errors.push(Error::custom("second"));
     }
 
+    #[test]
+    fn accum_rollback_discards_later_errors() {
+        let mut errs = Error::accumulator();
+        errs.push(Error::custom("kept"));
+        let savepoint = errs.savepoint();
+        errs.push(Error::custom("discarded"));
+        errs.push(Error::custom("also discarded"));
+        errs.rollback(savepoint);
+
+        let err = errs.finish().unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert_eq!(err.to_string(), "kept");
+    }
+
+    #[test]
+    fn accum_rollback_keeps_errors_without_rollback() {
+        let mut errs = Error::accumulator();
+        let _savepoint = errs.savepoint();
+        errs.push(Error::custom("committed"));
+
+        let err = errs.finish().unwrap_err();
+        assert_eq!(err.len(), 1);
+    }
+
+    #[test]
+    fn accum_rollback_to_empty() {
+        let mut errs = Error::accumulator();
+        let savepoint = errs.savepoint();
+        errs.push(Error::custom("discarded"));
+        errs.rollback(savepoint);
+        errs.finish().unwrap();
+    }
+
     #[test]
     fn accum_checkpoint_error() {
         let mut errs = Error::accumulator();
@@ -23,5 +23,27 @@ pub trait FromAttributes: Sized {
     /// #[serde(borrow, rename_all = "camel_case")]
     /// pub struct JoinedExample {}
     /// ```
+    ///
+    /// # Namespaced Attributes
+    /// When deriving, `#[darling(attributes(...))]` accepts multi-segment paths such as
+    /// `my_macro::inner`. Attribute paths are matched in full, so `#[darling(attributes(my_macro::inner))]`
+    /// will parse `#[my_macro::inner(...)]` while ignoring `#[inner(...)]`, `#[my_macro(...)]`,
+    /// and built-in attributes like `#[doc]`.
+    ///
+    /// # Forwarding All Attributes
+    /// A derived impl may omit `attributes(...)` if it sets `forward_attrs`. With a bare
+    /// `#[darling(forward_attrs)]`, every attribute, including `#[doc]`, is collected into
+    /// the `attrs: Vec<syn::Attribute>` field, which is useful for pass-through macros.
     fn from_attributes(attrs: &[Attribute]) -> Result<Self>;
+
+    /// Create an instance by parsing a list of attributes, and also return the attributes
+    /// which this type doesn't parse, in their original order.
+    ///
+    /// This is useful for macros which rewrite an item and need to re-emit foreign
+    /// attributes untouched. Derived impls return every attribute whose path is not
+    /// listed in `#[darling(attributes(...))]`. The default implementation can't tell
+    /// which attributes were parsed, so it returns all of them.
+    fn from_attributes_with_rest(attrs: &[Attribute]) -> Result<(Self, Vec<Attribute>)> {
+        Self::from_attributes(attrs).map(|value| (value, attrs.to_vec()))
+    }
 }
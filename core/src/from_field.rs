@@ -5,6 +5,15 @@ use crate::Result;
 /// Creates an instance by parsing an individual field and its attributes.
 pub trait FromField: Sized {
     fn from_field(field: &Field) -> Result<Self>;
+
+    /// Creates an instance from a field and its position among its siblings, counting from zero.
+    ///
+    /// [`ast::Fields`](crate::ast::Fields) uses this for both named and tuple fields. The default
+    /// implementation ignores the index and calls [`FromField::from_field`].
+    #[allow(unused_variables)]
+    fn from_field_at(field: &Field, index: usize) -> Result<Self> {
+        Self::from_field(field)
+    }
 }
 
 impl FromField for () {
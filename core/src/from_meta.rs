@@ -6,9 +6,11 @@ use std::collections::hash_map::HashMap;
 use std::collections::HashSet;
 use std::hash::BuildHasher;
 use std::num;
+use std::ops;
 use std::rc::Rc;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
+use std::time::Duration;
 use syn::spanned::Spanned;
 
 use syn::{Expr, Ident, Lit, Meta, Path};
@@ -28,7 +30,7 @@ use crate::{Error, Result};
 ///
 /// * Word with no value specified - becomes `true`.
 /// * As a boolean literal, e.g. `foo = true`.
-/// * As a string literal, e.g. `foo = "true"`.
+/// * As a string literal, e.g. `foo = "true"`. Case is ignored, so `foo = "False"` also works.
 ///
 /// ## char
 /// * As a char literal, e.g. `foo = '#'`.
@@ -37,15 +39,100 @@ use crate::{Error, Result};
 /// ## String
 /// * As a string literal, e.g. `foo = "hello"`.
 /// * As a raw string literal, e.g. `foo = r#"hello "world""#`.
+/// * As a `concat!` of string literals, e.g. `foo = concat!("hello", " world")`.
+///   This works for every type parsed from a string.
+/// * `Cow<str>` accepts the same formats and always produces `Cow::Owned`.
+/// * `syn::LitStr` accepts a string literal and keeps it verbatim, including its span and any
+///   suffix, which is useful when the string is forwarded into generated code.
 ///
 /// ## Number
 /// * As a string literal, e.g. `foo = "-25"`.
 /// * As an unquoted positive value, e.g. `foo = 404`. Negative numbers must be in quotation marks.
+/// * Unsigned types reject a negative literal such as `foo = -1` with an error naming the value.
+/// * `f32` and `f64` also accept integer literals, e.g. `foo = 2`, and the strings `"inf"`,
+///   `"-inf"`, and `"nan"`.
+/// * `NonZero*` types accept the same formats as their underlying integer, and reject zero.
+///
+/// ## IpAddr, Ipv4Addr, Ipv6Addr, and SocketAddr
+/// * As a string literal, e.g. `foo = "127.0.0.1:8080"` or `foo = "[::1]:443"`.
+///
+/// ## Duration
+/// * As a string literal with a unit suffix, e.g. `foo = "500ms"`. Supported units are
+///   `ns`, `us` (or `µs`), `ms`, `s`, `m`, and `h`.
+/// * Bare integers such as `foo = 500` are rejected, since their unit would be ambiguous.
+///
+/// ## Range and RangeInclusive
+/// * As an unquoted range expression, e.g. `foo = 0..256` or `foo = 0..=255`, with each bound
+///   parsed using the range's item type.
+/// * Both bounds are required; open-ended ranges such as `foo = 0..` are rejected.
+///
+/// ## Bound
+/// * As a list containing exactly one of `inclusive = value`, `exclusive = value`, or
+///   `unbounded`, e.g. `min(exclusive = 0)`, producing `Included`, `Excluded`, or `Unbounded`
+///   respectively. The value is parsed using the bound's item type.
+///
+/// ## NaiveDate, NaiveTime, and NaiveDateTime
+/// Requires the `chrono` feature.
+/// * As an ISO 8601 string literal, e.g. `foo = "2024-01-31"`, `foo = "13:45:00"`, or
+///   `foo = "2024-01-31T13:45:00"`.
+///
+/// ## glob::Pattern
+/// Requires the `glob` feature.
+/// * As a non-empty string literal containing a glob pattern, e.g. `include = "src/**/*.rs"`.
+///   Invalid patterns are rejected with the position of the syntax error.
+///
+/// ## semver::Version and semver::VersionReq
+/// Requires the `semver` feature.
+/// * As a string literal, e.g. `min_version = "1.2.3"` or `requires = ">=1.2, <2"`.
+///   A `Version` must have all three components, while a `VersionReq` accepts partial versions.
+///
+/// ## Url
+/// Requires the `url` feature.
+/// * As a string literal containing an absolute URL, e.g. `foo = "https://example.com/api"`.
+///   Relative URLs are rejected, since there is no base to resolve them against.
+///
+/// ## Uuid
+/// Requires the `uuid` feature.
+/// * As a string literal in hyphenated or simple form, e.g.
+///   `id = "550e8400-e29b-41d4-a716-446655440000"` or `id = "550e8400e29b41d4a716446655440000"`.
 ///
 /// ## ()
 /// * Word with no value specified, e.g. `foo`. This is best used with `Option`.
 ///   See `darling::util::Flag` for a more strongly-typed alternative.
 ///
+/// ## HashMap, BTreeMap, and IndexMap
+/// * As a list of named items, e.g. `foo(first = "a", second = "b")`. Each item's path becomes
+///   the key and its value is parsed using the map's value type.
+/// * Keys may be `String`, `syn::Ident`, or (for `HashMap` and `IndexMap` only) `syn::Path`. Attribute syntax
+///   requires keys to be paths, so numeric keys are not supported.
+/// * Repeated keys produce a duplicate field error for each repetition, rather than silently
+///   overwriting the earlier value.
+/// * `HashMap` iteration order is unspecified. If generated code iterates the map, use
+///   `BTreeMap` for key order, or `IndexMap` (requires the `indexmap` feature) for source
+///   order, so that macro output is reproducible.
+///
+/// ## `Vec<String>` and `Vec` of unsigned integers
+/// * As a list, e.g. `foo("a", "b")` or `foo(1, 2, 3)`. An empty list, `foo()`, produces an empty `Vec`.
+/// * As an array, e.g. `foo = ["a", "b"]` or `foo = [1, 2, 3]`. Integer arrays may also be quoted,
+///   e.g. `foo = "[1, 2, 3]"`.
+/// * For `Vec<u8>`, as a byte string literal, e.g. `foo = b"\x7fELF"`, preserving the exact bytes.
+///   A plain string is always read as a quoted array, so `foo = "ELF"` is an error rather than
+///   the string's UTF-8 bytes; use a byte string literal for text.
+/// * These read all values from a single occurrence of the field. To instead collect a value
+///   from each repeated occurrence, such as `foo = "a", foo = "b"`, mark the field
+///   `#[darling(multiple)]` and use the element type's own impl.
+/// * `Vec<SpannedValue<T>>` accepts a list or array of any `T`, giving each element the span
+///   of its own item.
+/// * `Vec<Indexed<T>>` accepts a list or array of any `T`, recording each element's
+///   position in the list, starting from zero.
+///
+/// ## Arrays
+/// * As a list containing exactly `N` items, e.g. `foo(1, 2, 3)` for `[u8; 3]`.
+///
+/// ## Tuples
+/// * As a list of positional values, e.g. `foo(3, 4)`, for tuples of two to six elements.
+/// * As a tuple expression, e.g. `foo = (3, 4)`.
+///
 /// ## Option
 /// * Any format produces `Some`.
 ///
@@ -107,6 +194,25 @@ pub trait FromMeta: Sized {
         Err(Error::unsupported_format("list"))
     }
 
+    /// Create an instance from the comma-separated arguments of an attribute macro, such as
+    /// the `args` passed to a `#[proc_macro_attribute]` function. The items are parsed as a
+    /// list of [`NestedMeta`] and passed to [`FromMeta::from_list`]; an empty stream becomes
+    /// an empty list. This generally should not be overridden by implementers.
+    ///
+    /// ```rust,ignore
+    /// #[proc_macro_attribute]
+    /// pub fn your_attr(args: TokenStream, input: TokenStream) -> TokenStream {
+    ///     let args = match MacroArgs::from_token_stream(args.into()) {
+    ///         Ok(v) => v,
+    ///         Err(e) => return e.write_errors().into(),
+    ///     };
+    ///     // ...
+    /// }
+    /// ```
+    fn from_token_stream(tokens: proc_macro2::TokenStream) -> Result<Self> {
+        Self::from_list(&NestedMeta::parse_meta_list(tokens)?)
+    }
+
     /// Create an instance from a literal value of either `foo = "bar"` or `foo("bar")`.
     /// This dispatches to the appropriate method based on the type of literal encountered,
     /// and generally should not be overridden by implementers.
@@ -135,6 +241,9 @@ pub trait FromMeta: Sized {
                 // we just propagate the call to the inner expression.
                 Self::from_expr(&group.expr)
             }
+            Expr::Macro(ref mac) if mac.mac.path.is_ident("concat") => {
+                Self::from_string(&concat_str_lits(&mac.mac)?)
+            }
             _ => Err(Error::unexpected_expr_type(expr)),
         }
         .map_err(|e| e.with_span(expr))
@@ -284,8 +393,15 @@ impl FromMeta for bool {
         Ok(value)
     }
 
+    /// Accepts `"true"` and `"false"`, ignoring case.
     fn from_string(value: &str) -> Result<Self> {
-        value.parse().map_err(|_| Error::unknown_value(value))
+        if value.eq_ignore_ascii_case("true") {
+            Ok(true)
+        } else if value.eq_ignore_ascii_case("false") {
+            Ok(false)
+        } else {
+            Err(Error::unknown_value_with_alts(value, &["true", "false"]))
+        }
     }
 }
 
@@ -311,9 +427,33 @@ impl FromMeta for char {
         if let (Some(char), None) = (char1, char2) {
             Ok(char)
         } else {
-            Err(Error::unexpected_type("string"))
+            Err(Error::custom(format!(
+                "expected a single character, found {} characters",
+                s.chars().count()
+            )))
+        }
+    }
+}
+
+/// Evaluate `concat!("a", "b")` to `"ab"`. Only string literals are supported as arguments.
+fn concat_str_lits(mac: &syn::Macro) -> Result<String> {
+    let args =
+        mac.parse_body_with(syn::punctuated::Punctuated::<Expr, syn::Token![,]>::parse_terminated)?;
+    let mut errors = Error::accumulator();
+    let mut value = String::new();
+
+    for arg in &args {
+        match arg {
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Str(ref lit),
+                ..
+            }) => value.push_str(&lit.value()),
+            _ => errors
+                .push(Error::custom("`concat!` arguments must be string literals").with_span(arg)),
         }
     }
+
+    errors.finish_with(value)
 }
 
 impl FromMeta for String {
@@ -322,16 +462,59 @@ impl FromMeta for String {
     }
 }
 
+/// Parsed values are always `Cow::Owned`; a `Cow::Borrowed` can still be supplied
+/// by a default, such as `#[darling(default = || Cow::Borrowed("..."))]`.
+impl<'a> FromMeta for Cow<'a, str> {
+    fn from_string(s: &str) -> Result<Self> {
+        Ok(Cow::Owned(s.to_string()))
+    }
+}
+
+/// Paths are constructed verbatim from the string, without normalization, so that
+/// the same attribute produces the same path on every platform.
 impl FromMeta for std::path::PathBuf {
     fn from_string(s: &str) -> Result<Self> {
+        if s.is_empty() {
+            return Err(Error::custom("path must not be empty"));
+        }
+
         Ok(s.into())
     }
 }
 
+/// Parse an integer literal such as `404` or `-25`.
+fn int_lit<T>(lit: &syn::LitInt) -> Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    lit.base10_parse().map_err(Error::from)
+}
+
+/// Parse an integer literal into an unsigned type, explaining why a negative literal
+/// such as `-1` is rejected rather than reporting an invalid digit.
+fn unsigned_int_lit<T>(lit: &syn::LitInt) -> Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    if lit.base10_digits().starts_with('-') {
+        return Err(Error::custom(format!(
+            "expected a non-negative integer, found {}",
+            lit.base10_digits()
+        )));
+    }
+
+    int_lit(lit)
+}
+
 /// Generate an impl of `FromMeta` that will accept strings which parse to numbers or
-/// integer literals.
+/// integer literals. Integer literals are read with `$parse_int`, which defaults to `int_lit`.
 macro_rules! from_meta_num {
     ($ty:path) => {
+        from_meta_num!($ty, int_lit);
+    };
+    ($ty:path, $parse_int:ident) => {
         impl FromMeta for $ty {
             fn from_string(s: &str) -> Result<Self> {
                 s.parse().map_err(|_| Error::unknown_value(s))
@@ -340,7 +523,7 @@ macro_rules! from_meta_num {
             fn from_value(value: &Lit) -> Result<Self> {
                 (match *value {
                     Lit::Str(ref s) => Self::from_string(&s.value()),
-                    Lit::Int(ref s) => s.base10_parse::<$ty>().map_err(Error::from),
+                    Lit::Int(ref s) => $parse_int::<$ty>(s),
                     _ => Err(Error::unexpected_lit_type(value)),
                 })
                 .map_err(|e| e.with_span(value))
@@ -349,30 +532,48 @@ macro_rules! from_meta_num {
     };
 }
 
-from_meta_num!(u8);
-from_meta_num!(u16);
-from_meta_num!(u32);
-from_meta_num!(u64);
-from_meta_num!(u128);
-from_meta_num!(usize);
+from_meta_num!(u8, unsigned_int_lit);
+from_meta_num!(u16, unsigned_int_lit);
+from_meta_num!(u32, unsigned_int_lit);
+from_meta_num!(u64, unsigned_int_lit);
+from_meta_num!(u128, unsigned_int_lit);
+from_meta_num!(usize, unsigned_int_lit);
 from_meta_num!(i8);
 from_meta_num!(i16);
 from_meta_num!(i32);
 from_meta_num!(i64);
 from_meta_num!(i128);
 from_meta_num!(isize);
-from_meta_num!(num::NonZeroU8);
-from_meta_num!(num::NonZeroU16);
-from_meta_num!(num::NonZeroU32);
-from_meta_num!(num::NonZeroU64);
-from_meta_num!(num::NonZeroU128);
-from_meta_num!(num::NonZeroUsize);
-from_meta_num!(num::NonZeroI8);
-from_meta_num!(num::NonZeroI16);
-from_meta_num!(num::NonZeroI32);
-from_meta_num!(num::NonZeroI64);
-from_meta_num!(num::NonZeroI128);
-from_meta_num!(num::NonZeroIsize);
+/// Generate an impl of `FromMeta` for a `NonZero*` type, which parses its underlying integer
+/// type and then rejects zero.
+macro_rules! from_meta_nonzero {
+    ($ty:path, $inner:ident) => {
+        impl FromMeta for $ty {
+            fn from_string(s: &str) -> Result<Self> {
+                <$ty>::new($inner::from_string(s)?)
+                    .ok_or_else(|| Error::custom("expected a non-zero value"))
+            }
+
+            fn from_value(value: &Lit) -> Result<Self> {
+                <$ty>::new($inner::from_value(value)?)
+                    .ok_or_else(|| Error::custom("expected a non-zero value").with_span(value))
+            }
+        }
+    };
+}
+
+from_meta_nonzero!(num::NonZeroU8, u8);
+from_meta_nonzero!(num::NonZeroU16, u16);
+from_meta_nonzero!(num::NonZeroU32, u32);
+from_meta_nonzero!(num::NonZeroU64, u64);
+from_meta_nonzero!(num::NonZeroU128, u128);
+from_meta_nonzero!(num::NonZeroUsize, usize);
+from_meta_nonzero!(num::NonZeroI8, i8);
+from_meta_nonzero!(num::NonZeroI16, i16);
+from_meta_nonzero!(num::NonZeroI32, i32);
+from_meta_nonzero!(num::NonZeroI64, i64);
+from_meta_nonzero!(num::NonZeroI128, i128);
+from_meta_nonzero!(num::NonZeroIsize, isize);
 
 /// Generate an impl of `FromMeta` that will accept strings which parse to floats or
 /// float literals.
@@ -387,6 +588,7 @@ macro_rules! from_meta_float {
                 (match *value {
                     Lit::Str(ref s) => Self::from_string(&s.value()),
                     Lit::Float(ref s) => s.base10_parse::<$ty>().map_err(Error::from),
+                    Lit::Int(ref s) => s.base10_parse::<$ty>().map_err(Error::from),
                     _ => Err(Error::unexpected_lit_type(value)),
                 })
                 .map_err(|e| e.with_span(value))
@@ -398,6 +600,248 @@ macro_rules! from_meta_float {
 from_meta_float!(f32);
 from_meta_float!(f64);
 
+/// Generate an impl of `FromMeta` that will accept string literals and parse them using
+/// the type's `FromStr` impl, preserving the parse error's message.
+macro_rules! from_meta_net {
+    ($ty:path) => {
+        impl FromMeta for $ty {
+            fn from_string(s: &str) -> Result<Self> {
+                s.parse()
+                    .map_err(|e: std::net::AddrParseError| Error::custom(e))
+            }
+        }
+    };
+}
+
+from_meta_net!(std::net::IpAddr);
+from_meta_net!(std::net::Ipv4Addr);
+from_meta_net!(std::net::Ipv6Addr);
+from_meta_net!(std::net::SocketAddr);
+
+/// Parsing for human-readable durations, i.e. `timeout = "30s"`.
+impl FromMeta for Duration {
+    fn from_string(value: &str) -> Result<Self> {
+        let unit_start = value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len());
+        let (amount, unit) = value.split_at(unit_start);
+
+        if amount.is_empty() {
+            return Err(Error::custom(format!(
+                "expected a duration such as \"30s\", found \"{}\"",
+                value
+            )));
+        }
+
+        let amount: u64 = amount
+            .parse()
+            .map_err(|_| Error::custom(format!("duration value `{}` is too large", amount)))?;
+
+        let overflow = || Error::custom(format!("duration `{}` is too large", value));
+
+        match unit {
+            "ns" => Ok(Duration::from_nanos(amount)),
+            "us" | "µs" => Ok(Duration::from_micros(amount)),
+            "ms" => Ok(Duration::from_millis(amount)),
+            "s" => Ok(Duration::from_secs(amount)),
+            "m" => amount
+                .checked_mul(60)
+                .map(Duration::from_secs)
+                .ok_or_else(overflow),
+            "h" => amount
+                .checked_mul(60 * 60)
+                .map(Duration::from_secs)
+                .ok_or_else(overflow),
+            "" => Err(Error::custom(format!(
+                "duration `{}` is missing a unit; add one of `ns`, `us`, `ms`, `s`, `m`, or `h`",
+                value
+            ))),
+            _ => Err(Error::custom(format!(
+                "unknown duration unit `{}`; expected one of `ns`, `us`, `ms`, `s`, `m`, or `h`",
+                unit
+            ))),
+        }
+    }
+
+    fn from_value(value: &Lit) -> Result<Self> {
+        (match *value {
+            Lit::Str(ref s) => Self::from_string(&s.value()),
+            Lit::Int(_) => Err(Error::custom(
+                "durations must be string literals with a unit, e.g. \"30s\"",
+            )),
+            _ => Err(Error::unexpected_lit_type(value)),
+        })
+        .map_err(|e| e.with_span(value))
+    }
+}
+
+/// Read both bounds of a range expression such as `0..256`, checking that it uses the
+/// expected `..` or `..=` limits.
+fn range_bounds<T: FromMeta>(expr: &Expr, inclusive: bool) -> Result<(T, T)> {
+    let range = syn::ExprRange::from_expr(expr)?;
+    let expected = if inclusive { "..=" } else { ".." };
+
+    if matches!(range.limits, syn::RangeLimits::Closed(_)) != inclusive {
+        return Err(
+            Error::custom(format!("expected a range using `{}`", expected)).with_span(&range),
+        );
+    }
+
+    let (start, end) = match (&range.start, &range.end) {
+        (Some(start), Some(end)) => (start, end),
+        _ => {
+            return Err(Error::custom(format!(
+                "expected a range with both bounds, such as `0{}10`",
+                expected
+            ))
+            .with_span(&range))
+        }
+    };
+
+    let mut errors = Error::accumulator();
+    let start = errors.handle(T::from_expr(start));
+    let end = errors.handle(T::from_expr(end));
+    errors.finish()?;
+
+    // Both values are present because no errors were recorded.
+    Ok((start.unwrap(), end.unwrap()))
+}
+
+/// Parsing for unquoted range expressions, i.e. `bits = 0..256`.
+impl<T: FromMeta> FromMeta for ops::Range<T> {
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        range_bounds(expr, false).map(|(start, end)| start..end)
+    }
+}
+
+/// Parsing for unquoted inclusive range expressions, i.e. `bits = 0..=255`.
+impl<T: FromMeta> FromMeta for ops::RangeInclusive<T> {
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        range_bounds(expr, true).map(|(start, end)| start..=end)
+    }
+}
+
+/// Parsing for a single bound, i.e. `min(inclusive = 1)`, `min(exclusive = 0)`, or `max(unbounded)`.
+impl<T: FromMeta> FromMeta for ops::Bound<T> {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        const KINDS: &[&str] = &["inclusive", "exclusive", "unbounded"];
+
+        let item = match items {
+            [item] => item,
+            _ => {
+                return Err(Error::custom(
+                    "expected exactly one of `inclusive`, `exclusive`, or `unbounded`",
+                ))
+            }
+        };
+
+        let meta = match item {
+            NestedMeta::Meta(meta) => meta,
+            NestedMeta::NameValueInvalidExpr(ref inner) => {
+                return Err(Error::unsupported_format("expression").with_span(&inner.path))
+            }
+            NestedMeta::Lit(lit) => return Err(Error::unsupported_format("literal").with_span(lit)),
+        };
+
+        let path = meta.path();
+        let bound = if path.is_ident("inclusive") {
+            T::from_meta(meta).map(ops::Bound::Included)
+        } else if path.is_ident("exclusive") {
+            T::from_meta(meta).map(ops::Bound::Excluded)
+        } else if path.is_ident("unbounded") {
+            <()>::from_meta(meta).map(|_| ops::Bound::Unbounded)
+        } else {
+            return Err(Error::unknown_field_path_with_alts(path, KINDS).with_span(path));
+        };
+
+        bound.map_err(|e| e.at_path(path))
+    }
+}
+
+/// Generate an impl of `FromMeta` for a `chrono` type that parses ISO 8601 string literals
+/// using the type's `FromStr` impl.
+#[cfg(feature = "chrono")]
+macro_rules! from_meta_chrono {
+    ($ty:path, $kind:literal, $example:literal) => {
+        impl FromMeta for $ty {
+            fn from_string(s: &str) -> Result<Self> {
+                s.parse().map_err(|e: chrono::ParseError| {
+                    Error::custom(format!(
+                        concat!(
+                            "invalid ",
+                            $kind,
+                            " `{}`: {}; expected e.g. \"",
+                            $example,
+                            "\""
+                        ),
+                        s, e
+                    ))
+                })
+            }
+        }
+    };
+}
+
+#[cfg(feature = "chrono")]
+from_meta_chrono!(chrono::NaiveDate, "date", "2024-01-31");
+#[cfg(feature = "chrono")]
+from_meta_chrono!(chrono::NaiveTime, "time", "13:45:00");
+#[cfg(feature = "chrono")]
+from_meta_chrono!(
+    chrono::NaiveDateTime,
+    "date and time",
+    "2024-01-31T13:45:00"
+);
+
+#[cfg(feature = "url")]
+impl FromMeta for url::Url {
+    fn from_string(value: &str) -> Result<Self> {
+        url::Url::parse(value).map_err(|e| match e {
+            url::ParseError::RelativeUrlWithoutBase => Error::custom(format!(
+                "invalid URL `{}`: expected an absolute URL such as \"https://example.com\"",
+                value
+            )),
+            e => Error::custom(format!("invalid URL `{}`: {}", value, e)),
+        })
+    }
+}
+
+#[cfg(feature = "semver")]
+impl FromMeta for semver::Version {
+    fn from_string(value: &str) -> Result<Self> {
+        semver::Version::parse(value)
+            .map_err(|e| Error::custom(format!("invalid version `{}`: {}", value, e)))
+    }
+}
+
+#[cfg(feature = "semver")]
+impl FromMeta for semver::VersionReq {
+    fn from_string(value: &str) -> Result<Self> {
+        semver::VersionReq::parse(value)
+            .map_err(|e| Error::custom(format!("invalid version requirement `{}`: {}", value, e)))
+    }
+}
+
+#[cfg(feature = "glob")]
+impl FromMeta for glob::Pattern {
+    fn from_string(value: &str) -> Result<Self> {
+        if value.is_empty() {
+            return Err(Error::custom("glob pattern cannot be empty"));
+        }
+
+        glob::Pattern::new(value)
+            .map_err(|e| Error::custom(format!("invalid glob pattern `{}`: {}", value, e)))
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl FromMeta for uuid::Uuid {
+    fn from_string(value: &str) -> Result<Self> {
+        uuid::Uuid::parse_str(value)
+            .map_err(|e| Error::custom(format!("invalid UUID `{}`: {}", value, e)))
+    }
+}
+
 /// Parsing support for punctuated. This attempts to preserve span information
 /// when available, but also supports parsing strings with the call site as the
 /// emitted span.
@@ -635,29 +1079,42 @@ impl FromMeta for syn::TypePath {
     }
 }
 
+/// Parse each item of a list such as `foo("a", "b")`, collecting every error.
+fn list_items<T: FromMeta>(items: &[NestedMeta]) -> Result<Vec<T>> {
+    let mut errors = Error::accumulator();
+    let values = items
+        .iter()
+        .filter_map(|item| errors.handle(T::from_nested_meta(item)))
+        .collect();
+
+    errors.finish_with(values)
+}
+
+/// Parse each element of an array such as `foo = ["a", "b"]`, collecting every error.
+fn array_items<T: FromMeta>(array: &syn::ExprArray) -> Result<Vec<T>> {
+    let mut errors = Error::accumulator();
+    let values = array
+        .elems
+        .iter()
+        .filter_map(|expr| errors.handle(T::from_expr(expr).map_err(|e| e.with_span(expr))))
+        .collect();
+
+    errors.finish_with(values)
+}
+
+/// Generate an impl of `FromMeta` for a `Vec` of unsigned integers. Additional literal
+/// patterns can be given to accept literal types other than strings and arrays.
 macro_rules! from_numeric_array {
-    ($ty:ident) => {
-        /// Parsing an unsigned integer array, i.e. `example = "[1, 2, 3, 4]"`.
+    ($ty:ident $(, $lit_pat:pat => $lit_expr:expr)*) => {
+        /// Parsing an unsigned integer array, i.e. `example = "[1, 2, 3, 4]"` or `example(1, 2, 3, 4)`.
         impl FromMeta for Vec<$ty> {
+            fn from_list(items: &[NestedMeta]) -> Result<Self> {
+                list_items(items)
+            }
+
             fn from_expr(expr: &syn::Expr) -> Result<Self> {
                 match expr {
-                    syn::Expr::Array(expr_array) => expr_array
-                        .elems
-                        .iter()
-                        .map(|expr| {
-                            let unexpected = || {
-                                Error::custom("Expected array of unsigned integers").with_span(expr)
-                            };
-                            match expr {
-                                Expr::Lit(lit) => $ty::from_value(&lit.lit),
-                                Expr::Group(group) => match &*group.expr {
-                                    Expr::Lit(lit) => $ty::from_value(&lit.lit),
-                                    _ => Err(unexpected()),
-                                },
-                                _ => Err(unexpected()),
-                            }
-                        })
-                        .collect::<Result<Vec<$ty>>>(),
+                    syn::Expr::Array(expr_array) => array_items(expr_array),
                     syn::Expr::Lit(expr_lit) => Self::from_value(&expr_lit.lit),
                     syn::Expr::Group(group) => Self::from_expr(&group.expr), // see FromMeta::from_expr
                     _ => Err(Error::unexpected_expr_type(expr)),
@@ -665,19 +1122,83 @@ macro_rules! from_numeric_array {
             }
 
             fn from_value(value: &Lit) -> Result<Self> {
-                let expr_array = syn::ExprArray::from_value(value)?;
-                Self::from_expr(&syn::Expr::Array(expr_array))
+                match *value {
+                    $($lit_pat => $lit_expr,)*
+                    _ => {
+                        let expr_array = syn::ExprArray::from_value(value)?;
+                        Self::from_expr(&syn::Expr::Array(expr_array))
+                    }
+                }
             }
         }
     };
 }
 
-from_numeric_array!(u8);
+// Byte strings are accepted as-is, so `example = b"\x7fELF"` keeps the exact bytes.
+from_numeric_array!(u8, Lit::ByteStr(ref bytes) => Ok(bytes.value()));
 from_numeric_array!(u16);
 from_numeric_array!(u32);
 from_numeric_array!(u64);
 from_numeric_array!(usize);
 
+/// Parsing a list of strings, i.e. `example("a", "b")` or `example = ["a", "b"]`.
+impl FromMeta for Vec<String> {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        list_items(items)
+    }
+
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Array(expr_array) => array_items(expr_array),
+            Expr::Group(group) => Self::from_expr(&group.expr), // see FromMeta::from_expr
+            _ => Err(Error::unexpected_expr_type(expr)),
+        }
+        .map_err(|e| e.with_span(expr))
+    }
+}
+
+/// Each element keeps the span of its own item, rather than that of the whole list.
+impl<T: FromMeta> FromMeta for Vec<crate::util::SpannedValue<T>> {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        list_items(items)
+    }
+
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Array(expr_array) => array_items(expr_array),
+            Expr::Group(group) => Self::from_expr(&group.expr), // see FromMeta::from_expr
+            _ => Err(Error::unexpected_expr_type(expr)),
+        }
+        .map_err(|e| e.with_span(expr))
+    }
+}
+
+/// Each element records its position in the list, counting from zero.
+impl<T: FromMeta> FromMeta for Vec<crate::util::Indexed<T>> {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        list_items(items).map(index_values)
+    }
+
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Array(expr_array) => array_items(expr_array).map(index_values),
+            Expr::Group(group) => Self::from_expr(&group.expr), // see FromMeta::from_expr
+            _ => Err(Error::unexpected_expr_type(expr)),
+        }
+        .map_err(|e| e.with_span(expr))
+    }
+}
+
+/// Pair each value with its position. The helpers fail if any item fails, so every
+/// value that reaches this point keeps its original index.
+fn index_values<T>(values: Vec<T>) -> Vec<crate::util::Indexed<T>> {
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| crate::util::Indexed::new(index, value))
+        .collect()
+}
+
 impl FromMeta for syn::Lit {
     fn from_value(value: &Lit) -> Result<Self> {
         Ok(value.clone())
@@ -698,10 +1219,7 @@ macro_rules! from_meta_lit {
 
         impl FromMeta for Vec<$impl_ty> {
             fn from_list(items: &[NestedMeta]) -> Result<Self> {
-                items
-                    .iter()
-                    .map(<$impl_ty as FromMeta>::from_nested_meta)
-                    .collect()
+                list_items(items)
             }
 
             fn from_value(value: &syn::Lit) -> Result<Self> {
@@ -711,11 +1229,7 @@ macro_rules! from_meta_lit {
 
             fn from_expr(expr: &syn::Expr) -> Result<Self> {
                 match expr {
-                    syn::Expr::Array(expr_array) => expr_array
-                        .elems
-                        .iter()
-                        .map(<$impl_ty as FromMeta>::from_expr)
-                        .collect::<Result<Vec<_>>>(),
+                    syn::Expr::Array(expr_array) => array_items(expr_array),
                     syn::Expr::Lit(expr_lit) => Self::from_value(&expr_lit.lit),
                     syn::Expr::Group(g) => Self::from_expr(&g.expr),
                     _ => Err(Error::unexpected_expr_type(expr)),
@@ -829,6 +1343,111 @@ impl<T: FromMeta> FromMeta for ::std::result::Result<T, Meta> {
     }
 }
 
+/// Parses a list of exactly `N` items, e.g. `foo(1, 2, 3)` for `[u8; 3]`.
+impl<T: FromMeta, const N: usize> FromMeta for [T; N] {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        if items.len() != N {
+            let error = Error::custom(format!(
+                "expected {} item{}, found {}",
+                N,
+                if N == 1 { "" } else { "s" },
+                items.len()
+            ));
+
+            // Point at the first surplus item if there is one; otherwise the caller
+            // will attach the span of the whole list.
+            return Err(match items.get(N) {
+                Some(extra) => error.with_span(extra),
+                None => error,
+            });
+        }
+
+        Ok(list_items::<T>(items)?
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("length was checked above")))
+    }
+}
+
+/// Check that a list meant for a tuple contains exactly `expected` positional items.
+fn check_tuple_items(items: &[NestedMeta], expected: usize) -> Result<()> {
+    let mut errors = Error::accumulator();
+
+    for item in items {
+        let path = match item {
+            NestedMeta::Meta(Meta::NameValue(nv)) => &nv.path,
+            NestedMeta::NameValueInvalidExpr(nv) => &nv.path,
+            _ => continue,
+        };
+
+        errors.push(
+            Error::custom(format!(
+                "expected a positional value, found named item `{}`",
+                path_to_string(path)
+            ))
+            .with_span(item),
+        );
+    }
+
+    if items.len() != expected {
+        errors.push(Error::custom(format!(
+            "expected {} items, found {}",
+            expected,
+            items.len()
+        )));
+    }
+
+    errors.finish()
+}
+
+/// Generate an impl of `FromMeta` for a tuple, which accepts either a list of positional
+/// values, e.g. `foo(3, 4)`, or a tuple expression, e.g. `foo = (3, 4)`.
+macro_rules! from_meta_tuple {
+    ($len:literal; $($ty:ident $idx:tt),+) => {
+        impl<$($ty: FromMeta),+> FromMeta for ($($ty,)+) {
+            fn from_list(items: &[NestedMeta]) -> Result<Self> {
+                check_tuple_items(items, $len)?;
+
+                let mut errors = Error::accumulator();
+                let values = ($(errors.handle($ty::from_nested_meta(&items[$idx])),)+);
+                errors.finish()?;
+
+                Ok(($(values.$idx.expect("errors were checked above"),)+))
+            }
+
+            fn from_expr(expr: &Expr) -> Result<Self> {
+                match expr {
+                    Expr::Tuple(tuple) => {
+                        if tuple.elems.len() != $len {
+                            return Err(Error::custom(format!(
+                                "expected {} items, found {}",
+                                $len,
+                                tuple.elems.len()
+                            ))
+                            .with_span(expr));
+                        }
+
+                        let elems = tuple.elems.iter().collect::<Vec<_>>();
+                        let mut errors = Error::accumulator();
+                        let values = ($(errors.handle($ty::from_expr(elems[$idx])),)+);
+                        errors.finish()?;
+
+                        Ok(($(values.$idx.expect("errors were checked above"),)+))
+                    }
+                    Expr::Group(group) => Self::from_expr(&group.expr), // see FromMeta::from_expr
+                    _ => Err(Error::unexpected_expr_type(expr)),
+                }
+                .map_err(|e| e.with_span(expr))
+            }
+        }
+    };
+}
+
+from_meta_tuple!(2; A 0, B 1);
+from_meta_tuple!(3; A 0, B 1, C 2);
+from_meta_tuple!(4; A 0, B 1, C 2, D 3);
+from_meta_tuple!(5; A 0, B 1, C 2, D 3, E 4);
+from_meta_tuple!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+
 /// Trait to convert from a path into an owned key for a map.
 trait KeyFromPath: Sized {
     fn from_path(path: &syn::Path) -> Result<Self>;
@@ -883,6 +1502,17 @@ macro_rules! map {
         }
     };
 
+    (index_map, $key:ty, $nested:ident) => {
+        #[cfg(feature = "indexmap")]
+        impl<V: FromMeta, S: BuildHasher + Default> FromMeta for indexmap::IndexMap<$key, V, S> {
+            map!(
+                indexmap::IndexMap::with_capacity_and_hasher($nested.len(), Default::default()),
+                $key,
+                $nested
+            );
+        }
+    };
+
     (btree_map, $key:ty, $nested:ident) => {
         impl<V: FromMeta> FromMeta for BTreeMap<$key, V> {
             map!(BTreeMap::new(), $key, $nested);
@@ -974,6 +1604,10 @@ map!(hash_map, String, nested);
 map!(hash_map, syn::Ident, nested);
 map!(hash_map, syn::Path, nested);
 
+map!(index_map, String, nested);
+map!(index_map, syn::Ident, nested);
+map!(index_map, syn::Path, nested);
+
 map!(btree_map, String, nested);
 map!(btree_map, syn::Ident, nested);
 
@@ -1154,9 +1788,13 @@ impl FromMeta for HashSet<Path> {
 #[cfg(test)]
 mod tests {
     use std::{
+        borrow::Cow,
         collections::HashSet,
         fmt::Debug,
-        num::{NonZeroU32, NonZeroU64},
+        num::{NonZeroU32, NonZeroU64, NonZeroU8},
+        ops,
+        rc::Rc,
+        sync::Arc,
     };
 
     use proc_macro2::TokenStream;
@@ -1227,6 +1865,18 @@ mod tests {
         // string literals
         assert_eq!(fm::<bool>(quote!(ignore = "true")), true);
         assert_eq!(fm::<bool>(quote!(ignore = "false")), false);
+        assert_eq!(fm::<bool>(quote!(ignore = "TRUE")), true);
+        assert_eq!(fm::<bool>(quote!(ignore = "False")), false);
+    }
+
+    #[test]
+    fn bool_bad_string_fails() {
+        let err = pnm::<bool>(quote!("yes")).unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(
+            err.to_string(),
+            "Unknown value: `yes`. Available values: `false`, `true`"
+        );
     }
 
     #[test]
@@ -1239,20 +1889,71 @@ mod tests {
     }
 
     #[test]
-    fn string_succeeds() {
-        // cooked form
-        assert_eq!(&fm::<String>(quote!(ignore = "world")), "world");
-
-        // raw form
-        assert_eq!(&fm::<String>(quote!(ignore = r#"world"#)), "world");
+    fn char_delimiter() {
+        assert_eq!(fm::<char>(quote!(ignore = ';')), ';');
+        assert_eq!(fm::<char>(quote!(ignore = ";")), ';');
     }
 
     #[test]
-    fn pathbuf_succeeds() {
+    fn char_rejects_multiple_characters() {
+        let err = pnm::<char>(quote!(";;")).unwrap_err();
+        assert!(err.has_span());
         assert_eq!(
-            fm::<std::path::PathBuf>(quote!(ignore = r#"C:\"#)),
+            err.to_string(),
+            "expected a single character, found 2 characters"
+        );
+
+        let err = pnm::<char>(quote!("")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a single character, found 0 characters"
+        );
+    }
+
+    #[test]
+    fn string_succeeds() {
+        // cooked form
+        assert_eq!(&fm::<String>(quote!(ignore = "world")), "world");
+
+        // raw form
+        assert_eq!(&fm::<String>(quote!(ignore = r#"world"#)), "world");
+    }
+
+    #[test]
+    fn cow_str_succeeds() {
+        let value = fm::<Cow<'static, str>>(quote!(ignore = "world"));
+        assert!(matches!(value, Cow::Owned(_)));
+        assert_eq!(value, "world");
+    }
+
+    #[test]
+    fn smart_pointer_succeeds() {
+        assert_eq!(*fm::<Box<String>>(quote!(ignore = "world")), "world");
+        assert_eq!(*fm::<Rc<u32>>(quote!(ignore = 7)), 7);
+        assert!(*fm::<Arc<bool>>(quote!(ignore)));
+    }
+
+    #[test]
+    fn pathbuf_succeeds() {
+        assert_eq!(
+            fm::<std::path::PathBuf>(quote!(ignore = r#"C:\"#)),
             std::path::PathBuf::from(r#"C:\"#)
         );
+        assert_eq!(
+            fm::<std::path::PathBuf>(quote!(ignore = "shaders/main.wgsl")),
+            std::path::PathBuf::from("shaders/main.wgsl")
+        );
+        assert_eq!(
+            fm::<std::path::PathBuf>(quote!(ignore = "/usr/share/../lib")),
+            std::path::PathBuf::from("/usr/share/../lib")
+        );
+    }
+
+    #[test]
+    fn pathbuf_empty_fails() {
+        let err = pnm::<std::path::PathBuf>(quote!("")).unwrap_err();
+        assert_eq!(err.to_string(), "path must not be empty");
+        assert!(err.has_span());
     }
 
     #[test]
@@ -1263,12 +1964,391 @@ mod tests {
         assert_eq!(fm::<f64>(quote!(ignore = "1.4e10")), 1.4e10);
     }
 
-    #[should_panic(expected = "UnknownValue")]
+    #[test]
+    fn net_addrs_succeed() {
+        use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+        assert_eq!(
+            fm::<IpAddr>(quote!(ignore = "10.0.0.1")),
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))
+        );
+        assert_eq!(
+            fm::<Ipv4Addr>(quote!(ignore = "127.0.0.1")),
+            Ipv4Addr::LOCALHOST
+        );
+        assert_eq!(fm::<Ipv6Addr>(quote!(ignore = "::1")), Ipv6Addr::LOCALHOST);
+        assert_eq!(
+            fm::<SocketAddr>(quote!(ignore = "127.0.0.1:8080")),
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 8080)
+        );
+        assert_eq!(
+            fm::<SocketAddr>(quote!(ignore = "[::1]:443")),
+            SocketAddr::new(Ipv6Addr::LOCALHOST.into(), 443)
+        );
+    }
+
+    #[test]
+    fn net_addrs_reject_malformed_input() {
+        use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+        assert!(pnm::<IpAddr>(quote!("not an ip")).unwrap_err().has_span());
+        assert!(pnm::<Ipv4Addr>(quote!("256.0.0.1")).unwrap_err().has_span());
+        assert!(pnm::<Ipv6Addr>(quote!("127.0.0.1")).unwrap_err().has_span());
+        assert!(pnm::<SocketAddr>(quote!("::1:443")).unwrap_err().has_span());
+    }
+
+    #[test]
+    fn range_succeeds() {
+        assert_eq!(fm::<ops::Range<usize>>(quote!(ignore = 0..256)), 0..256);
+        assert_eq!(fm::<ops::Range<u32>>(quote!(ignore = "5..10")), 5..10);
+    }
+
+    #[test]
+    fn range_inclusive_succeeds() {
+        assert_eq!(
+            fm::<ops::RangeInclusive<u8>>(quote!(ignore = 0..=255)),
+            0..=255
+        );
+    }
+
+    #[test]
+    fn range_open_ended_fails() {
+        for input in [
+            quote!(ignore = 0..),
+            quote!(ignore = ..10),
+            quote!(ignore = ..),
+        ] {
+            let err = ops::Range::<usize>::from_meta(&pm(input).unwrap()).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "expected a range with both bounds, such as `0..10`"
+            );
+            assert!(err.has_span());
+        }
+    }
+
+    #[test]
+    fn range_wrong_limits_fails() {
+        let err = ops::RangeInclusive::<usize>::from_meta(&pm(quote!(ignore = 0..10)).unwrap())
+            .unwrap_err();
+        assert_eq!(err.to_string(), "expected a range using `..=`");
+
+        let err =
+            ops::Range::<usize>::from_meta(&pm(quote!(ignore = 0..=10)).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "expected a range using `..`");
+    }
+
+    #[test]
+    fn range_bad_bounds_fail() {
+        let err = ops::Range::<u8>::from_meta(&pm(quote!(ignore = 300..x)).unwrap()).unwrap_err();
+        assert_eq!(err.len(), 2);
+    }
+
+    #[test]
+    fn bound_succeeds() {
+        assert_eq!(
+            fm::<ops::Bound<u8>>(quote!(ignore(inclusive = 1))),
+            ops::Bound::Included(1)
+        );
+        assert_eq!(
+            fm::<ops::Bound<i32>>(quote!(ignore(exclusive = -5))),
+            ops::Bound::Excluded(-5)
+        );
+        assert_eq!(
+            fm::<ops::Bound<u8>>(quote!(ignore(unbounded))),
+            ops::Bound::Unbounded
+        );
+    }
+
+    #[test]
+    fn bound_requires_one_item() {
+        for input in [
+            quote!(ignore()),
+            quote!(ignore(inclusive = 1, exclusive = 2)),
+        ] {
+            let err = ops::Bound::<u8>::from_meta(&pm(input).unwrap()).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "expected exactly one of `inclusive`, `exclusive`, or `unbounded`"
+            );
+            assert!(err.has_span());
+        }
+    }
+
+    #[test]
+    fn bound_errors() {
+        let err =
+            ops::Bound::<u8>::from_meta(&pm(quote!(ignore(exclusive = 300))).unwrap()).unwrap_err();
+        assert!(err.to_string().ends_with(" at exclusive"));
+
+        let err =
+            ops::Bound::<u8>::from_meta(&pm(quote!(ignore(unbounded = 1))).unwrap()).unwrap_err();
+        assert!(err.to_string().ends_with(" at unbounded"));
+
+        let err =
+            ops::Bound::<u8>::from_meta(&pm(quote!(ignore(inclusiv = 1))).unwrap()).unwrap_err();
+        assert!(err.to_string().starts_with("Unknown field: `inclusiv`"));
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn url_absolute() {
+        assert_eq!(
+            fm::<url::Url>(quote!(ignore = "https://api.example.com/v1")).as_str(),
+            "https://api.example.com/v1"
+        );
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn url_relative_fails() {
+        let err = pnm::<url::Url>(quote!("/v1/users")).unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(
+            err.to_string(),
+            "invalid URL `/v1/users`: expected an absolute URL such as \"https://example.com\""
+        );
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn url_malformed_fails() {
+        let err = pnm::<url::Url>(quote!("https://exa mple.com")).unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(
+            err.to_string(),
+            "invalid URL `https://exa mple.com`: invalid international domain name"
+        );
+    }
+
+    #[cfg(feature = "glob")]
+    #[test]
+    fn glob_pattern() {
+        let pattern = fm::<glob::Pattern>(quote!(ignore = "src/**/*.rs"));
+        assert_eq!(pattern.as_str(), "src/**/*.rs");
+        assert!(pattern.matches("src/util/mod.rs"));
+    }
+
+    #[cfg(feature = "glob")]
+    #[test]
+    fn glob_pattern_invalid_fails() {
+        let err = pnm::<glob::Pattern>(quote!("[")).unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(
+            err.to_string(),
+            "invalid glob pattern `[`: Pattern syntax error near position 0: invalid range pattern"
+        );
+    }
+
+    #[cfg(feature = "glob")]
+    #[test]
+    fn glob_pattern_empty_fails() {
+        let err = pnm::<glob::Pattern>(quote!("")).unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(err.to_string(), "glob pattern cannot be empty");
+    }
+
+    #[test]
+    fn string_raw() {
+        assert_eq!(
+            fm::<String>(quote!(ignore = r#"hello "world""#)),
+            "hello \"world\""
+        );
+    }
+
+    #[test]
+    fn string_concat() {
+        assert_eq!(fm::<String>(quote!(ignore = concat!("a", "b"))), "ab");
+        assert_eq!(
+            fm::<String>(quote!(ignore = concat!("src/", r"lib.rs",))),
+            "src/lib.rs"
+        );
+        assert_eq!(fm::<u16>(quote!(ignore = concat!("80", "80"))), 8080);
+    }
+
+    #[test]
+    fn string_concat_non_literal_fails() {
+        let err = FromMeta::from_meta(&pm(quote!(ignore = concat!("a", env!("B")))).unwrap())
+            .map(|_: String| ())
+            .unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(
+            err.to_string(),
+            "`concat!` arguments must be string literals"
+        );
+    }
+
+    #[test]
+    fn string_other_macro_fails() {
+        let err = FromMeta::from_meta(&pm(quote!(ignore = stringify!(a))).unwrap())
+            .map(|_: String| ())
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Unexpected type `macro`");
+    }
+
+    #[cfg(feature = "semver")]
+    #[test]
+    fn semver_version() {
+        assert_eq!(
+            fm::<semver::Version>(quote!(ignore = "1.2.3-beta.1")),
+            semver::Version::parse("1.2.3-beta.1").unwrap()
+        );
+    }
+
+    #[cfg(feature = "semver")]
+    #[test]
+    fn semver_version_partial_fails() {
+        let err = pnm::<semver::Version>(quote!("1.2")).unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(
+            err.to_string(),
+            "invalid version `1.2`: unexpected end of input while parsing minor version number"
+        );
+    }
+
+    #[cfg(feature = "semver")]
+    #[test]
+    fn semver_version_req() {
+        let req = fm::<semver::VersionReq>(quote!(ignore = "1.2"));
+        assert!(req.matches(&semver::Version::new(1, 3, 0)));
+        assert!(!req.matches(&semver::Version::new(2, 0, 0)));
+    }
+
+    #[cfg(feature = "semver")]
+    #[test]
+    fn semver_version_req_invalid_fails() {
+        let err = pnm::<semver::VersionReq>(quote!(">=1.x.y")).unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(
+            err.to_string(),
+            "invalid version requirement `>=1.x.y`: unexpected character after wildcard in version req"
+        );
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid_forms() {
+        let expected = uuid::Uuid::from_u128(0x550e8400_e29b_41d4_a716_446655440000);
+        assert_eq!(
+            fm::<uuid::Uuid>(quote!(ignore = "550e8400-e29b-41d4-a716-446655440000")),
+            expected
+        );
+        assert_eq!(
+            fm::<uuid::Uuid>(quote!(ignore = "550e8400e29b41d4a716446655440000")),
+            expected
+        );
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid_malformed_fails() {
+        let err = pnm::<uuid::Uuid>(quote!("550e8400-e29b-41d4-a716")).unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(
+            err.to_string(),
+            "invalid UUID `550e8400-e29b-41d4-a716`: invalid group count: expected 5, found 4"
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_naive_date() {
+        assert_eq!(
+            fm::<chrono::NaiveDate>(quote!(ignore = "2024-01-31")),
+            chrono::NaiveDate::from_ymd_opt(2024, 1, 31).unwrap()
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_naive_time() {
+        assert_eq!(
+            fm::<chrono::NaiveTime>(quote!(ignore = "13:45:00")),
+            chrono::NaiveTime::from_hms_opt(13, 45, 0).unwrap()
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_naive_date_time() {
+        assert_eq!(
+            fm::<chrono::NaiveDateTime>(quote!(ignore = "2024-01-31T13:45:00")),
+            chrono::NaiveDate::from_ymd_opt(2024, 1, 31)
+                .unwrap()
+                .and_hms_opt(13, 45, 0)
+                .unwrap()
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_malformed_date() {
+        let err = pnm::<chrono::NaiveDate>(quote!("2024-02-30")).unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(
+            err.to_string(),
+            "invalid date `2024-02-30`: input is out of range; expected e.g. \"2024-01-31\""
+        );
+    }
+
+    #[test]
+    fn duration_succeeds() {
+        use std::time::Duration;
+
+        assert_eq!(
+            fm::<Duration>(quote!(ignore = "500ms")),
+            Duration::from_millis(500)
+        );
+        assert_eq!(
+            fm::<Duration>(quote!(ignore = "1h")),
+            Duration::from_secs(3600)
+        );
+    }
+
+    #[test]
+    fn duration_unknown_unit() {
+        let err = pnm::<std::time::Duration>(quote!("10furlongs")).unwrap_err();
+        assert!(err.has_span());
+        assert!(err.to_string().contains("`furlongs`"));
+    }
+
+    #[test]
+    fn duration_rejects_bare_int() {
+        let err = pnm::<std::time::Duration>(quote!(10)).unwrap_err();
+        assert!(err.has_span());
+    }
+
+    #[test]
+    fn duration_overflow() {
+        pnm::<std::time::Duration>(quote!("18446744073709551615h")).unwrap_err();
+        pnm::<std::time::Duration>(quote!("99999999999999999999s")).unwrap_err();
+    }
+
+    #[should_panic(expected = "expected a non-zero value")]
     #[test]
     fn nonzero_number_fails() {
         fm::<NonZeroU64>(quote!(ignore = "0"));
     }
 
+    #[test]
+    fn nonzero_u8() {
+        assert_eq!(
+            fm::<NonZeroU8>(quote!(ignore = 4)),
+            NonZeroU8::new(4).unwrap()
+        );
+
+        let zero = pnm::<NonZeroU8>(quote!(0)).unwrap_err();
+        assert!(zero.has_span());
+        assert_eq!(zero.to_string(), "expected a non-zero value");
+
+        let out_of_range = pnm::<NonZeroU8>(quote!(256)).unwrap_err();
+        assert!(out_of_range.has_span());
+        assert_eq!(
+            out_of_range.to_string(),
+            pnm::<u8>(quote!(256)).unwrap_err().to_string()
+        );
+    }
+
     #[test]
     fn nonzero_number_succeeds() {
         assert_eq!(
@@ -1293,6 +2373,19 @@ mod tests {
         assert_eq!(fm::<i32>(quote!(ignore = -255)), -255i32);
     }
 
+    #[test]
+    fn negative_int_for_unsigned() {
+        let err = u32::from_meta(&pm(quote!(count = -1)).unwrap()).unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(err.to_string(), "expected a non-negative integer, found -1");
+
+        let err = NonZeroU8::from_meta(&pm(quote!(count = -20)).unwrap()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a non-negative integer, found -20"
+        );
+    }
+
     #[test]
     #[allow(clippy::float_cmp)] // we want exact equality
     fn float_without_quotes() {
@@ -1301,6 +2394,21 @@ mod tests {
         assert_eq!(fm::<f64>(quote!(ignore = 1.4e10)), 1.4e10f64);
     }
 
+    #[test]
+    #[allow(clippy::float_cmp)] // we want exact equality
+    fn float_from_int_literal() {
+        assert_eq!(fm::<f64>(quote!(scale = 2)), 2.0f64);
+        assert_eq!(fm::<f32>(quote!(scale = -3)), -3.0f32);
+        assert_eq!(fm::<f64>(quote!(scale = 5u8)), 5.0f64);
+    }
+
+    #[test]
+    fn float_special_strings() {
+        assert!(fm::<f64>(quote!(scale = "nan")).is_nan());
+        assert_eq!(fm::<f32>(quote!(scale = "inf")), f32::INFINITY);
+        assert_eq!(fm::<f64>(quote!(scale = "-inf")), f64::NEG_INFINITY);
+    }
+
     #[test]
     fn too_large_int_produces_error() {
         assert!(fm::<Result<u8>>(quote!(ignore = 2000)).is_err());
@@ -1365,6 +2473,32 @@ mod tests {
         assert!(errors[2].has_span());
     }
 
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn index_map_preserves_order() {
+        use indexmap::IndexMap;
+
+        let parse =
+            || fm::<IndexMap<String, u8>>(quote!(ignore(zulu = 1, alpha = 2, mike = 3, bravo = 4)));
+        let first = parse();
+
+        assert_eq!(
+            first.keys().map(String::as_str).collect::<Vec<_>>(),
+            vec!["zulu", "alpha", "mike", "bravo"]
+        );
+        assert!(first.iter().eq(parse().iter()));
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn index_map_duplicate() {
+        let err = indexmap::IndexMap::<syn::Ident, bool>::from_meta(
+            &pm(quote!(ignore(hello, hello = false))).unwrap(),
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "Duplicate field `hello`");
+    }
+
     #[test]
     fn hash_map_ident_succeeds() {
         use std::collections::HashMap;
@@ -1591,6 +2725,88 @@ mod tests {
             .unwrap_err();
     }
 
+    #[test]
+    fn array_succeeds() {
+        assert_eq!(
+            fm::<[String; 3]>(quote!(ignore("red", "green", "blue"))),
+            ["red", "green", "blue"]
+        );
+        assert_eq!(fm::<[u8; 2]>(quote!(ignore(1, 2))), [1, 2]);
+    }
+
+    #[test]
+    fn array_too_few() {
+        let err = <[u8; 3]>::from_meta(&pm(quote!(ignore(1, 2))).unwrap()).unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(err.to_string(), "expected 3 items, found 2");
+    }
+
+    #[test]
+    fn array_too_many() {
+        let err = <[u8; 1]>::from_meta(&pm(quote!(ignore(1, 2, 3))).unwrap()).unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(err.to_string(), "expected 1 item, found 3");
+    }
+
+    #[test]
+    fn array_element_errors() {
+        let err = <[u8; 3]>::from_meta(&pm(quote!(ignore(1, "x", 300))).unwrap()).unwrap_err();
+        assert_eq!(err.len(), 2);
+    }
+
+    #[test]
+    fn vec_indexed() {
+        use crate::util::Indexed;
+
+        let list = fm::<Vec<Indexed<String>>>(quote!(ignore("stage_a", "stage_b", "stage_c")));
+        assert_eq!(
+            list.iter().map(Indexed::index).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert_eq!(*list[2], "stage_c");
+
+        let array = fm::<Vec<Indexed<u8>>>(quote!(ignore = [7, 8]));
+        assert_eq!(array, vec![Indexed::new(0, 7), Indexed::new(1, 8)]);
+    }
+
+    #[test]
+    fn vec_indexed_errors() {
+        let err =
+            Vec::<crate::util::Indexed<u8>>::from_meta(&pm(quote!(ignore(1, "x", 300))).unwrap())
+                .unwrap_err();
+        assert_eq!(err.len(), 2);
+    }
+
+    #[test]
+    fn tuple_succeeds() {
+        assert_eq!(fm::<(i32, i32)>(quote!(ignore(3, -4))), (3, -4));
+        assert_eq!(fm::<(i32, i32)>(quote!(ignore = (3, 4))), (3, 4));
+        assert_eq!(
+            fm::<(String, u8, bool)>(quote!(ignore("x", 1, true))),
+            ("x".to_string(), 1, true)
+        );
+    }
+
+    #[test]
+    fn tuple_arity_mismatch() {
+        let err = <(u8, u8)>::from_meta(&pm(quote!(ignore(1, 2, 3))).unwrap()).unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(err.to_string(), "expected 2 items, found 3");
+
+        let err = <(u8, u8, u8)>::from_meta(&pm(quote!(ignore = (1, 2))).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "expected 3 items, found 2");
+    }
+
+    #[test]
+    fn tuple_rejects_named_items() {
+        let err = <(u8, u8)>::from_meta(&pm(quote!(ignore(1, y = 2))).unwrap()).unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(
+            err.to_string(),
+            "expected a positional value, found named item `y`"
+        );
+    }
+
     /// Tests that fallible parsing will always produce an outer `Ok` (from `fm`),
     /// and will accurately preserve the inner contents.
     #[test]
@@ -1675,6 +2891,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn number_list() {
+        assert_eq!(fm::<Vec<u16>>(quote!(ignore(80, 443))), vec![80, 443]);
+        assert_eq!(fm::<Vec<u8>>(quote!(ignore())), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn string_list() {
+        assert_eq!(
+            fm::<Vec<String>>(quote!(ignore("a", "b"))),
+            vec!["a".to_string(), "b".to_string()]
+        );
+        assert_eq!(fm::<Vec<String>>(quote!(ignore())), Vec::<String>::new());
+    }
+
+    #[test]
+    fn string_array() {
+        assert_eq!(
+            fm::<Vec<String>>(quote!(ignore = ["a", "b"])),
+            vec!["a".to_string(), "b".to_string()]
+        );
+    }
+
+    #[test]
+    fn string_list_errors() {
+        let err = Vec::<String>::from_meta(&pm(quote!(ignore("a", 1, b))).unwrap()).unwrap_err();
+        assert_eq!(err.len(), 2);
+    }
+
+    #[test]
+    fn byte_str_succeeds() {
+        assert_eq!(fm::<Vec<u8>>(quote!(ignore = b"ELF")), b"ELF".to_vec());
+        assert_eq!(
+            fm::<Vec<u8>>(quote!(ignore = b"\x7fELF\0\n")),
+            vec![0x7f, b'E', b'L', b'F', 0, b'\n']
+        );
+    }
+
+    #[test]
+    fn plain_str_is_not_bytes() {
+        let err = Vec::<u8>::from_meta(&pm(quote!(ignore = "ELF")).unwrap()).unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(
+            fm::<Vec<u8>>(quote!(ignore = "[69, 76, 70]")),
+            b"ELF".to_vec()
+        );
+    }
+
+    #[test]
+    fn byte_str_rejected_for_wider_ints() {
+        pnm::<Vec<u16>>(quote!(b"ELF")).unwrap_err();
+    }
+
     #[test]
     fn test_lit_array() {
         fm::<Vec<syn::LitStr>>(quote!(ignore = "[\"Hello World\", \"Test Array\"]"));
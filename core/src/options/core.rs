@@ -4,7 +4,9 @@ use crate::ast::{Data, Fields, Style};
 use crate::codegen;
 use crate::codegen::PostfixTransform;
 use crate::error::Accumulator;
-use crate::options::{DefaultExpression, InputField, InputVariant, ParseAttribute, ParseData};
+use crate::options::{
+    DefaultExpression, InputField, InputVariant, ParseAttribute, ParseData, UnknownFields,
+};
 use crate::util::Flag;
 use crate::{Error, FromMeta, Result};
 
@@ -46,6 +48,9 @@ pub struct Core {
     /// Whether or not unknown fields should produce an error at compilation time.
     pub allow_unknown_fields: Option<bool>,
 
+    /// How unknown fields should be handled. Mutually exclusive with `allow_unknown_fields`.
+    pub unknown_fields: Option<UnknownFields>,
+
     /// Use implementation of the inner type
     pub transparent: Flag,
 }
@@ -69,10 +74,19 @@ impl Core {
             post_transform: Default::default(),
             bound: Default::default(),
             allow_unknown_fields: Default::default(),
+            unknown_fields: Default::default(),
             transparent: Default::default(),
         })
     }
 
+    /// The resolved handling for unknown fields, from either `unknown_fields` or the
+    /// legacy `allow_unknown_fields` option.
+    pub fn unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields
+            .or_else(|| self.allow_unknown_fields.map(UnknownFields::from))
+            .unwrap_or_default()
+    }
+
     fn as_codegen_default(&self) -> Option<codegen::DefaultExpression<'_>> {
         self.default.as_ref().map(|expr| match *expr {
             DefaultExpression::Explicit(ref callable) => {
@@ -84,6 +98,9 @@ impl Core {
                 // everything is fine.
                 panic!("DefaultExpression::Inherit is not valid at container level")
             }
+            DefaultExpression::Fallible(_) => {
+                panic!("DefaultExpression::Fallible is not valid at container level")
+            }
             DefaultExpression::Trait { span } => codegen::DefaultExpression::Trait { span },
         })
     }
@@ -128,7 +145,27 @@ impl ParseAttribute for Core {
                 return Err(Error::duplicate_field("allow_unknown_fields").with_span(mi));
             }
 
+            if self.unknown_fields.is_some() {
+                return Err(Error::custom(
+                    "`allow_unknown_fields` and `unknown_fields` cannot be used together",
+                )
+                .with_span(mi));
+            }
+
             self.allow_unknown_fields = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("unknown_fields") {
+            if self.unknown_fields.is_some() {
+                return Err(Error::duplicate_field("unknown_fields").with_span(mi));
+            }
+
+            if self.allow_unknown_fields.is_some() {
+                return Err(Error::custom(
+                    "`allow_unknown_fields` and `unknown_fields` cannot be used together",
+                )
+                .with_span(mi));
+            }
+
+            self.unknown_fields = FromMeta::from_meta(mi)?;
         } else if path.is_ident("transparent") {
             if self.transparent.is_present() {
                 return Err(Error::duplicate_field("transparent").with_span(mi));
@@ -198,13 +235,155 @@ impl ParseData for Core {
                 .collect();
 
             if flatten_targets.len() > 1 {
-                for flatten in flatten_targets {
+                for flatten in &flatten_targets {
                     errors.push(
                         Error::custom("`#[darling(flatten)]` can only be applied to one field")
                             .with_span(&flatten.span()),
                     );
                 }
             }
+
+            let rest_targets: Vec<_> = fields
+                .iter()
+                .filter_map(|field| {
+                    if field.rest.is_present() {
+                        Some(field.rest)
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            if rest_targets.len() > 1 {
+                for rest in &rest_targets {
+                    errors.push(
+                        Error::custom("`#[darling(rest)]` can only be applied to one field")
+                            .with_span(&rest.span()),
+                    );
+                }
+            }
+
+            // Positional fields are read from the front of the list, so they must be
+            // declared before any field that is read by name.
+            let mut seen_named = false;
+            for field in fields.iter() {
+                if !field.positional.is_present() {
+                    seen_named = true;
+                } else if seen_named {
+                    errors.push(
+                        Error::custom(
+                            "`#[darling(positional)]` fields must be declared before other fields",
+                        )
+                        .with_span(&field.positional.span()),
+                    );
+                }
+            }
+
+            // Both options claim all unknown meta items, so they can't coexist.
+            if !flatten_targets.is_empty() && !rest_targets.is_empty() {
+                for rest in rest_targets {
+                    errors.push(
+                        Error::custom("`#[darling(rest)]` cannot be used alongside `flatten`")
+                            .with_span(&rest.span()),
+                    );
+                }
+            }
+
+            if fields.style.is_struct() {
+                validate_warnings_field(&fields.fields, self.unknown_fields(), &self.ident, errors);
+                validate_aliases(&fields.fields, errors);
+            }
+        } else if let Data::Enum(variants) = &self.data {
+            for variant in variants {
+                variant.validate_fields(errors);
+            }
+        }
+    }
+}
+
+/// Check that each alias names only one field, and doesn't repeat its own field's name.
+pub(crate) fn validate_aliases(fields: &[InputField], errors: &mut Accumulator) {
+    let codegen_fields: Vec<_> = fields.iter().map(InputField::as_codegen_field).collect();
+
+    for (field, codegen_field) in fields.iter().zip(&codegen_fields) {
+        for alias in field.aliases.iter().chain(&field.deprecated_aliases) {
+            if codegen_field.as_name() == Some(alias.as_str()) {
+                errors.push(
+                    Error::custom(format!(
+                        "alias `{}` is already the name of this field",
+                        alias
+                    ))
+                    .with_span(&field.ident),
+                );
+            }
+
+            for (other, other_codegen) in fields.iter().zip(&codegen_fields) {
+                if std::ptr::eq(field, other) {
+                    continue;
+                }
+
+                let clashes = other_codegen.as_name() == Some(alias.as_str())
+                    || other.aliases.contains(alias)
+                    || other.deprecated_aliases.contains(alias);
+                if clashes {
+                    errors.push(
+                        Error::custom(format!(
+                            "alias `{}` is also accepted by field `{}`",
+                            alias, other.ident
+                        ))
+                        .with_span(&field.ident),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Check that a set of named fields has exactly one place to put warnings if parsing
+/// it can record any, so that no warning is silently dropped.
+pub(crate) fn validate_warnings_field(
+    fields: &[InputField],
+    unknown_fields: UnknownFields,
+    container: &syn::Ident,
+    errors: &mut Accumulator,
+) {
+    let targets: Vec<_> = fields
+        .iter()
+        .filter(|field| field.warnings.is_present())
+        .collect();
+
+    if targets.len() > 1 {
+        for target in &targets {
+            errors.push(
+                Error::custom("`#[darling(warnings)]` can only be applied to one field")
+                    .with_span(&target.warnings.span()),
+            );
+        }
+    }
+
+    if !targets.is_empty() {
+        return;
+    }
+
+    // Unknown fields are only warned about if no field claims them.
+    let claims_unknown = fields
+        .iter()
+        .any(|field| field.flatten.is_present() || field.rest.is_present());
+    if unknown_fields == UnknownFields::Warn && !claims_unknown {
+        errors.push(
+            Error::custom(
+                "`unknown_fields = \"warn\"` requires a field marked `#[darling(warnings)]`",
+            )
+            .with_span(container),
+        );
+    }
+
+    for field in fields {
+        if !field.deprecated_aliases.is_empty() {
+            errors.push(
+                Error::custom("`deprecated_alias` requires a field marked `#[darling(warnings)]`")
+                    .with_span(&field.ident),
+            );
         }
     }
 }
@@ -221,7 +400,7 @@ impl<'a> From<&'a Core> for codegen::TraitImpl<'a> {
                 .map_enum_variants(|variant| variant.as_codegen_variant(&v.ident)),
             default: v.as_codegen_default(),
             post_transform: v.post_transform.as_ref(),
-            allow_unknown_fields: v.allow_unknown_fields.unwrap_or_default(),
+            unknown_fields: v.unknown_fields(),
             transparent: v.transparent.is_present(),
             krate: v.krate.as_ref(),
         }
@@ -0,0 +1,20 @@
+use crate::{Error, FromMeta, Result};
+
+/// How a `multiple` field handles a value that it has already collected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dedup {
+    /// Report an error at the repeated value.
+    Error,
+    /// Silently drop the repeated value. This is the default for sets.
+    Ignore,
+}
+
+impl FromMeta for Dedup {
+    fn from_string(value: &str) -> Result<Self> {
+        match value {
+            "error" => Ok(Dedup::Error),
+            "ignore" => Ok(Dedup::Ignore),
+            other => Err(Error::unknown_value_with_alts(other, &["error", "ignore"])),
+        }
+    }
+}
@@ -30,7 +30,7 @@ impl FromAttributesOptions {
             })
             .unwrap_or(false);
 
-        if !is_transparent && opts.base.attr_names.is_empty() {
+        if !is_transparent && opts.base.attr_names.is_empty() && opts.base.forward_attrs.is_none() {
             Err(Error::custom(
                 "FromAttributes without attributes collects nothing",
             ))
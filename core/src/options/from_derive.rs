@@ -88,6 +88,7 @@ impl<'a> From<&'a FdiOptions> for FromDeriveInputImpl<'a> {
             data: v.data.as_ref(),
             generics: v.generics.as_ref(),
             forward_attrs: v.base.as_forward_attrs(),
+            attr_span: v.base.attr_span.as_ref(),
             supports: v.supports.as_ref(),
         }
     }
@@ -11,6 +11,7 @@ pub struct FromFieldOptions {
     pub base: OuterFrom,
     pub vis: Option<Ident>,
     pub ty: Option<Ident>,
+    pub index: Option<Ident>,
 }
 
 impl FromFieldOptions {
@@ -19,6 +20,7 @@ impl FromFieldOptions {
             base: OuterFrom::start(di)?,
             vis: Default::default(),
             ty: Default::default(),
+            index: Default::default(),
         })
         .parse_attributes(&di.attrs)?
         .parse_body(&di.data)
@@ -46,6 +48,10 @@ impl ParseData for FromFieldOptions {
                 self.ty.clone_from(&field.ident);
                 Ok(())
             }
+            Some("index") => {
+                self.index.clone_from(&field.ident);
+                Ok(())
+            }
             _ => self.base.parse_field(field),
         }
     }
@@ -61,9 +67,11 @@ impl<'a> From<&'a FromFieldOptions> for FromFieldImpl<'a> {
             ident: v.base.ident.as_ref(),
             vis: v.vis.as_ref(),
             ty: v.ty.as_ref(),
+            index: v.index.as_ref(),
             base: (&v.base.container).into(),
             attr_names: &v.base.attr_names,
             forward_attrs: v.base.as_forward_attrs(),
+            attr_span: v.base.attr_span.as_ref(),
             from_ident: v.base.from_ident,
         }
     }
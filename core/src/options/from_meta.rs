@@ -7,8 +7,8 @@ use syn::parse_quote;
 use crate::ast::Data;
 use crate::codegen::FromMetaImpl;
 use crate::error::Accumulator;
-use crate::options::{Core, ParseAttribute, ParseData};
-use crate::util::Callable;
+use crate::options::{Core, DefaultExpression, ParseAttribute, ParseData};
+use crate::util::{Callable, SpannedValue};
 use crate::{Error, FromMeta, Result};
 
 pub struct FromMetaOptions {
@@ -19,6 +19,11 @@ pub struct FromMetaOptions {
     from_none: Option<Callable>,
     /// Override for the default [`FromMeta::from_expr`] method.
     from_expr: Option<Callable>,
+    /// Override for the default [`FromMeta::from_string`] method, also used when a list
+    /// contains only a single string literal.
+    from_string: Option<Callable>,
+    /// The name of the item which selects the variant of an internally-tagged enum.
+    tag: Option<SpannedValue<String>>,
     /// Whether or not to derive [`syn::parse::Parse`] in addition to deriving [`FromMeta`].
     derive_syn_parse: Option<bool>,
 }
@@ -30,6 +35,8 @@ impl FromMetaOptions {
             from_word: None,
             from_none: None,
             from_expr: None,
+            from_string: None,
+            tag: None,
             derive_syn_parse: None,
         })
         .parse_attributes(&di.attrs)?
@@ -87,6 +94,18 @@ impl ParseAttribute for FromMetaOptions {
             }
 
             self.from_expr = FromMeta::from_meta(mi).map(Some)?;
+        } else if path.is_ident("from_string") {
+            if self.from_string.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(path));
+            }
+
+            self.from_string = FromMeta::from_meta(mi).map(Some)?;
+        } else if path.is_ident("tag") {
+            if self.tag.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(path));
+            }
+
+            self.tag = FromMeta::from_meta(mi).map(Some)?;
         } else if path.is_ident("derive_syn_parse") {
             if self.derive_syn_parse.is_some() {
                 return Err(Error::duplicate_field_path(path).with_span(path));
@@ -132,6 +151,70 @@ impl ParseData for FromMetaOptions {
                         errors.push(Error::custom("`from_expr` cannot be used on transparent structs because the implementation is entirely delegated to the inner type").with_span(from_expr));
                     }
                 }
+
+                if let Some(from_string) = &self.from_string {
+                    if (data.len() == 1 && data.style.is_tuple())
+                        || (data.style.is_struct() && self.base.transparent.is_present())
+                    {
+                        errors.push(Error::custom("`from_string` cannot be used on transparent structs because the implementation is entirely delegated to the inner type").with_span(from_string));
+                    }
+                }
+
+                if let Some(tag) = &self.tag {
+                    errors.push(
+                        Error::custom("`tag` can only be used on enums").with_span(&tag.span()),
+                    );
+                }
+
+                // Tuple structs read exactly one item per field, in order, so only the
+                // options which transform a parsed value apply to their fields.
+                if data.style.is_tuple() && data.len() > 1 {
+                    if self.base.default.is_some() {
+                        errors.push(
+                            Error::custom(
+                                "`default` is not supported on tuple structs with several fields",
+                            )
+                            .with_span(&self.base.ident),
+                        );
+                    }
+
+                    for field in data.iter() {
+                        for (name, present) in [
+                            (
+                                "alias",
+                                !field.aliases.is_empty() || !field.deprecated_aliases.is_empty(),
+                            ),
+                            (
+                                "default",
+                                field
+                                    .default
+                                    .as_ref()
+                                    .map_or(false, |d| !matches!(d, DefaultExpression::Inherit)),
+                            ),
+                            ("default_env", field.default_env.is_some()),
+                            ("missing_message", field.missing_message.is_some()),
+                            ("with", field.with.is_some()),
+                            ("skip", field.skip.is_some()),
+                            ("skip_if", field.skip_if.is_some()),
+                            ("multiple", field.multiple.is_some()),
+                            ("deny_empty", field.deny_empty.is_present()),
+                            ("flatten", field.flatten.is_present()),
+                            ("rest", field.rest.is_present()),
+                            ("positional", field.positional.is_present()),
+                            ("warnings", field.warnings.is_present()),
+                        ] {
+                            if present {
+                                errors.push(
+                                    Error::custom(format!(
+                                        "`{}` is not supported on tuple struct fields",
+                                        name
+                                    ))
+                                    .with_span(&field.ty),
+                                );
+                            }
+                        }
+                    }
+                }
             }
             Data::Enum(ref data) => {
                 let word_variants: Vec<_> = data
@@ -160,6 +243,49 @@ impl ParseData for FromMetaOptions {
                     }
                 }
 
+                let other_variants: Vec<_> = data
+                    .iter()
+                    .filter(|variant| variant.other.is_present())
+                    .collect();
+
+                for variant in &other_variants {
+                    if !variant.is_newtype_variant() {
+                        errors.push(
+                            Error::custom(
+                                "`#[darling(other)]` can only be applied to a newtype variant",
+                            )
+                            .with_span(&variant.other.span()),
+                        );
+                    }
+                }
+
+                if other_variants.len() > 1 {
+                    for variant in other_variants {
+                        errors.push(
+                            Error::custom("`#[darling(other)]` can only be applied to one variant")
+                                .with_span(&variant.other.span()),
+                        );
+                    }
+                }
+
+                if let Some(from_string) = &self.from_string {
+                    errors.push(
+                        Error::custom("`from_string` cannot be used on enums because it conflicts with the generated impl")
+                            .with_span(from_string),
+                    );
+                }
+
+                if self.tag.is_some() {
+                    for variant in data.iter().filter(|v| v.other.is_present()) {
+                        errors.push(
+                            Error::custom(
+                                "`#[darling(other)]` cannot be used in an enum with `tag`",
+                            )
+                            .with_span(&variant.other.span()),
+                        );
+                    }
+                }
+
                 if let Some(from_expr) = &self.from_expr {
                     if data.iter().any(|v| v.is_unit_variant() && !v.is_skipped()) {
                         errors.push(
@@ -180,6 +306,8 @@ impl<'a> From<&'a FromMetaOptions> for FromMetaImpl<'a> {
             from_word: v.from_word(),
             from_none: v.from_none.as_ref(),
             from_expr: v.from_expr.as_ref(),
+            from_string: v.from_string.as_ref(),
+            tag: v.tag.as_ref().map(|tag| tag.as_str()),
             derive_syn_parse: v.derive_syn_parse.unwrap_or_default(),
         }
     }
@@ -64,6 +64,7 @@ impl<'a> From<&'a FromTypeParamOptions> for FromTypeParamImpl<'a> {
             default: v.default.as_ref(),
             attr_names: &v.base.attr_names,
             forward_attrs: v.base.as_forward_attrs(),
+            attr_span: v.base.attr_span.as_ref(),
             from_ident: v.base.from_ident,
         }
     }
@@ -38,6 +38,7 @@ impl<'a> From<&'a FromVariantOptions> for FromVariantImpl<'a> {
             fields: v.fields.as_ref(),
             attr_names: &v.base.attr_names,
             forward_attrs: v.base.as_forward_attrs(),
+            attr_span: v.base.attr_span.as_ref(),
             from_ident: v.base.from_ident,
             supports: v.supports.as_ref(),
         }
@@ -1,9 +1,10 @@
 use std::borrow::Cow;
 
+use proc_macro2::Span;
 use syn::{parse_quote_spanned, spanned::Spanned};
 
 use crate::codegen;
-use crate::options::{Core, DefaultExpression, ParseAttribute};
+use crate::options::{Core, Dedup, DefaultExpression, ParseAttribute};
 use crate::util::{Callable, Flag, SpannedValue};
 use crate::{Error, FromMeta, Result};
 
@@ -11,16 +12,38 @@ use crate::{Error, FromMeta, Result};
 pub struct InputField {
     pub ident: syn::Ident,
     pub attr_name: Option<String>,
+    /// Additional names which are accepted for this field.
+    pub aliases: Vec<String>,
+    /// Deprecated names which are still accepted for this field, with a warning.
+    pub deprecated_aliases: Vec<String>,
     pub ty: syn::Type,
     pub default: Option<DefaultExpression>,
+    /// Replaces the text of the error reported when the field is missing.
+    pub missing_message: Option<String>,
+    /// An environment variable read at expansion time when the field is absent.
+    pub default_env: Option<SpannedValue<String>>,
     pub with: Option<Callable>,
 
     /// If `true`, generated code will not look for this field in the input meta item,
     /// instead always falling back to either `InputField::default` or `Default::default`.
     pub skip: Option<SpannedValue<bool>>,
-    pub post_transform: Option<codegen::PostfixTransform>,
+    /// A `fn() -> bool` which is called at parse time. If it returns `true`, the field
+    /// is skipped and its presence in the input is an error.
+    pub skip_if: Option<Callable>,
+    /// Transforms applied to the parsed value, in order. A `map` always precedes an `and_then`.
+    pub post_transform: Vec<codegen::PostfixTransform>,
     pub multiple: Option<bool>,
+    /// How a `multiple` field handles repeated values. Sets default to `Dedup::Ignore`.
+    pub dedup: Option<SpannedValue<Dedup>>,
+    /// If set, an explicitly empty list or array is an error.
+    pub deny_empty: Flag,
     pub flatten: Flag,
+    /// If set, this field receives all unknown meta items, keyed by their path.
+    pub rest: Flag,
+    /// If set, this field is read from a leading positional item instead of by name.
+    pub positional: Flag,
+    /// If set, this field receives the warnings recorded while parsing its container.
+    pub warnings: Flag,
 }
 
 impl InputField {
@@ -32,16 +55,40 @@ impl InputField {
                 .attr_name
                 .as_deref()
                 .map_or_else(|| Cow::Owned(self.ident.to_string()), Cow::Borrowed),
+            aliases: &self.aliases,
+            deprecated_aliases: &self.deprecated_aliases,
             ty: &self.ty,
             default_expression: self.as_codegen_default(),
+            missing_message: self.missing_message.as_deref(),
+            default_env: self.default_env.as_ref().map(|var| var.as_str()),
             with_callable: self.with.as_ref().map(|w| w.as_ref()).map_or_else(
                 || Cow::Owned(parse_quote_spanned!(self.ty.span()=> _darling::FromMeta::from_meta)),
                 Cow::Borrowed,
             ),
             skip: *self.skip.unwrap_or_default(),
-            post_transform: self.post_transform.as_ref(),
+            skip_if: self.skip_if.as_ref(),
+            post_transform: &self.post_transform,
             multiple: self.multiple.unwrap_or_default(),
+            dedup: self.dedup(),
+            deny_empty: self.deny_empty.is_present(),
             flatten: self.flatten.is_present(),
+            rest: self.rest.is_present(),
+            positional: self.positional.is_present(),
+            warnings: self.warnings.is_present(),
+        }
+    }
+
+    /// The duplicate handling for a `multiple` field, if any. Repeated values are kept
+    /// unless `dedup` is set or the field is a `HashSet` or `BTreeSet`.
+    fn dedup(&self) -> Option<Dedup> {
+        if !self.multiple.unwrap_or_default() {
+            return None;
+        }
+
+        match self.dedup {
+            Some(dedup) => Some(*dedup),
+            None if is_set_type(&self.ty) => Some(Dedup::Ignore),
+            None => None,
         }
     }
 
@@ -52,6 +99,9 @@ impl InputField {
             DefaultExpression::Explicit(ref callable) => {
                 codegen::DefaultExpression::Explicit(callable)
             }
+            DefaultExpression::Fallible(ref callable) => {
+                codegen::DefaultExpression::Fallible(callable)
+            }
             DefaultExpression::Inherit => codegen::DefaultExpression::Inherit(&self.ident),
             DefaultExpression::Trait { span } => codegen::DefaultExpression::Trait { span },
         })
@@ -62,12 +112,22 @@ impl InputField {
             ident,
             ty,
             attr_name: None,
+            aliases: Vec::new(),
+            deprecated_aliases: Vec::new(),
             default: None,
+            missing_message: None,
+            default_env: None,
             with: None,
             skip: None,
+            skip_if: None,
             post_transform: Default::default(),
             multiple: None,
+            dedup: None,
+            deny_empty: Default::default(),
             flatten: Default::default(),
+            rest: Default::default(),
+            positional: Default::default(),
+            warnings: Default::default(),
         }
     }
 
@@ -79,6 +139,156 @@ impl InputField {
         let ty = f.ty.clone();
         let base = Self::new(ident, ty).parse_attributes(&f.attrs)?;
 
+        if let Some(dedup) = &base.dedup {
+            if !base.multiple.unwrap_or_default() {
+                return Err(Error::custom("`dedup` can only be used with `multiple`")
+                    .with_span(&dedup.span()));
+            }
+        }
+
+        // These are checked after parsing so the options can be declared in any order.
+        // Each pair is listed from both sides; only the first option to conflict reports it.
+        let rename = base.attr_name.is_some();
+        let alias = !base.aliases.is_empty();
+        let deprecated_alias = !base.deprecated_aliases.is_empty();
+        let default = base.default.is_some();
+        let default_env = base.default_env.is_some();
+        let with = base.with.is_some();
+        let skip = base.skip.map(|v| *v).unwrap_or_default();
+        let skip_if = base.skip_if.is_some();
+        let multiple = base.multiple.unwrap_or(false);
+        let deny_empty = base.deny_empty.is_present();
+        let flatten = base.flatten.is_present();
+        let rest = base.rest.is_present();
+        let positional = base.positional.is_present();
+        let warnings = base.warnings.is_present();
+
+        if let Some(var) = &base.default_env {
+            reject_conflicts(
+                "default_env",
+                var.span(),
+                &[
+                    ("multiple", multiple),
+                    ("with", with),
+                    ("skip", skip),
+                    ("skip_if", skip_if),
+                    ("flatten", flatten),
+                    ("rest", rest),
+                    ("positional", positional),
+                    ("warnings", warnings),
+                ],
+            )?;
+        }
+
+        if deny_empty {
+            reject_conflicts(
+                "deny_empty",
+                base.deny_empty.span(),
+                &[
+                    ("skip", skip),
+                    ("skip_if", skip_if),
+                    ("flatten", flatten),
+                    ("rest", rest),
+                    ("positional", positional),
+                    ("warnings", warnings),
+                ],
+            )?;
+        }
+
+        if rest {
+            reject_conflicts(
+                "rest",
+                base.rest.span(),
+                &[
+                    ("rename", rename),
+                    ("default_env", default_env),
+                    ("with", with),
+                    ("skip", skip),
+                    ("skip_if", skip_if),
+                    ("multiple", multiple),
+                    ("deny_empty", deny_empty),
+                    ("flatten", flatten),
+                    ("positional", positional),
+                    ("warnings", warnings),
+                ],
+            )?;
+        }
+
+        if positional {
+            reject_conflicts(
+                "positional",
+                base.positional.span(),
+                &[
+                    ("default_env", default_env),
+                    ("with", with),
+                    ("skip", skip),
+                    ("skip_if", skip_if),
+                    ("multiple", multiple),
+                    ("deny_empty", deny_empty),
+                    ("flatten", flatten),
+                    ("rest", rest),
+                    ("warnings", warnings),
+                ],
+            )?;
+        }
+
+        if warnings {
+            reject_conflicts(
+                "warnings",
+                base.warnings.span(),
+                &[
+                    ("rename", rename),
+                    ("alias", alias),
+                    ("deprecated_alias", deprecated_alias),
+                    ("default", default),
+                    ("default_env", default_env),
+                    ("with", with),
+                    ("skip", skip),
+                    ("skip_if", skip_if),
+                    ("multiple", multiple),
+                    ("deny_empty", deny_empty),
+                    ("flatten", flatten),
+                    ("rest", rest),
+                    ("positional", positional),
+                ],
+            )?;
+        }
+
+        if flatten {
+            reject_conflicts(
+                "flatten",
+                base.flatten.span(),
+                &[
+                    ("rename", rename),
+                    ("default_env", default_env),
+                    ("with", with),
+                    ("skip", skip),
+                    ("skip_if", skip_if),
+                    ("multiple", multiple),
+                    ("deny_empty", deny_empty),
+                    ("rest", rest),
+                    ("positional", positional),
+                    ("warnings", warnings),
+                ],
+            )?;
+        }
+
+        if let Some(callable) = &base.skip_if {
+            reject_conflicts(
+                "skip_if",
+                callable.span(),
+                &[
+                    ("default_env", default_env),
+                    ("skip", base.skip.is_some()),
+                    ("deny_empty", deny_empty),
+                    ("flatten", flatten),
+                    ("rest", rest),
+                    ("positional", positional),
+                    ("warnings", warnings),
+                ],
+            )?;
+        }
+
         Ok(if let Some(container) = parent {
             base.with_inherited(container)
         } else {
@@ -131,105 +341,127 @@ impl ParseAttribute for InputField {
             }
 
             self.attr_name = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("alias") || path.is_ident("deprecated_alias") {
+            let alias: String = FromMeta::from_meta(mi)?;
+            if self.aliases.contains(&alias) || self.deprecated_aliases.contains(&alias) {
+                return Err(Error::duplicate_field(&alias).with_span(mi));
+            }
 
-            if self.flatten.is_present() {
+            if path.is_ident("alias") {
+                self.aliases.push(alias);
+            } else {
+                self.deprecated_aliases.push(alias);
+            }
+        } else if path.is_ident("default") || path.is_ident("default_with") {
+            let fallible = path.is_ident("default_with");
+            if let Some(default) = &self.default {
                 return Err(
-                    Error::custom("`flatten` and `rename` cannot be used together").with_span(mi),
+                    if fallible == matches!(default, DefaultExpression::Fallible(_)) {
+                        Error::duplicate_field_path(path)
+                    } else {
+                        Error::custom("`default` and `default_with` cannot be used together")
+                    }
+                    .with_span(mi),
                 );
             }
-        } else if path.is_ident("default") {
-            if self.default.is_some() {
+
+            self.default = if fallible {
+                Some(DefaultExpression::Fallible(FromMeta::from_meta(mi)?))
+            } else {
+                FromMeta::from_meta(mi)?
+            };
+        } else if path.is_ident("missing_message") {
+            if self.missing_message.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.missing_message = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("default_env") {
+            if self.default_env.is_some() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
             }
-            self.default = FromMeta::from_meta(mi)?;
+
+            self.default_env = FromMeta::from_meta(mi)?;
         } else if path.is_ident("with") {
             if self.with.is_some() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
             }
 
             self.with = Some(FromMeta::from_meta(mi)?);
-
-            if self.flatten.is_present() {
-                return Err(
-                    Error::custom("`flatten` and `with` cannot be used together").with_span(mi),
-                );
-            }
         } else if path.is_ident("skip") {
             if self.skip.is_some() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
             }
 
             self.skip = FromMeta::from_meta(mi)?;
-
-            if self.skip.map(|v| *v).unwrap_or_default() && self.flatten.is_present() {
-                return Err(
-                    Error::custom("`flatten` and `skip` cannot be used together").with_span(mi),
-                );
+        } else if path.is_ident("skip_if") {
+            if self.skip_if.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
             }
+
+            self.skip_if = Some(FromMeta::from_meta(mi)?);
         } else if path.is_ident("map") || path.is_ident("and_then") {
             let transformer = path.get_ident().unwrap().clone();
-            if let Some(post_transform) = &self.post_transform {
-                if transformer == post_transform.transformer {
-                    return Err(Error::duplicate_field_path(path).with_span(mi));
-                } else {
-                    return Err(Error::custom(format!(
-                        "Options `{}` and `{}` are mutually exclusive",
-                        transformer, post_transform.transformer
-                    ))
-                    .with_span(mi));
-                }
-            }
-
-            self.post_transform = Some(codegen::PostfixTransform::new(
-                transformer,
-                FromMeta::from_meta(mi)?,
-            ));
+            if self
+                .post_transform
+                .iter()
+                .any(|pt| pt.transformer == transformer)
+            {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            let post_transform =
+                codegen::PostfixTransform::new(transformer, FromMeta::from_meta(mi)?);
+
+            // `and_then` validates the final value, so it runs after `map` regardless
+            // of the order in which the two were declared.
+            if post_transform.transformer == "map" {
+                self.post_transform.insert(0, post_transform);
+            } else {
+                self.post_transform.push(post_transform);
+            }
         } else if path.is_ident("multiple") {
             if self.multiple.is_some() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
             }
 
             self.multiple = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("dedup") {
+            if self.dedup.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
 
-            if self.multiple.unwrap_or(false) && self.flatten.is_present() {
-                return Err(
-                    Error::custom("`flatten` and `multiple` cannot be used together").with_span(mi),
-                );
+            self.dedup = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("deny_empty") {
+            if self.deny_empty.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
             }
+
+            self.deny_empty = FromMeta::from_meta(mi)?;
         } else if path.is_ident("flatten") {
             if self.flatten.is_present() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
             }
 
             self.flatten = FromMeta::from_meta(mi)?;
-
-            let mut conflicts = Error::accumulator();
-
-            if self.multiple.unwrap_or(false) {
-                conflicts.push(
-                    Error::custom("`flatten` and `multiple` cannot be used together").with_span(mi),
-                );
-            }
-
-            if self.attr_name.is_some() {
-                conflicts.push(
-                    Error::custom("`flatten` and `rename` cannot be used together").with_span(mi),
-                );
+        } else if path.is_ident("rest") {
+            if self.rest.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
             }
 
-            if self.with.is_some() {
-                conflicts.push(
-                    Error::custom("`flatten` and `with` cannot be used together").with_span(mi),
-                );
+            self.rest = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("positional") {
+            if self.positional.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
             }
 
-            if self.skip.map(|v| *v).unwrap_or_default() {
-                conflicts.push(
-                    Error::custom("`flatten` and `skip` cannot be used together").with_span(mi),
-                );
+            self.positional = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("warnings") {
+            if self.warnings.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
             }
 
-            conflicts.finish()?;
+            self.warnings = FromMeta::from_meta(mi)?;
         } else {
             return Err(Error::unknown_field_path(path).with_span(mi));
         }
@@ -237,3 +469,35 @@ impl ParseAttribute for InputField {
         Ok(())
     }
 }
+
+/// Report each conflicting option which was set alongside `option`, at `span`.
+fn reject_conflicts(option: &str, span: Span, conflicts: &[(&str, bool)]) -> Result<()> {
+    let mut errors = Error::accumulator();
+
+    for (name, conflict) in conflicts {
+        if *conflict {
+            errors.push(
+                Error::custom(format!(
+                    "`{}` and `{}` cannot be used together",
+                    option, name
+                ))
+                .with_span(&span),
+            );
+        }
+    }
+
+    errors.finish()
+}
+
+/// Whether `ty` names a standard library set, which deduplicates its values by default
+/// when collected with `multiple`.
+fn is_set_type(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(ty) = ty {
+        ty.qself.is_none()
+            && ty.path.segments.last().map_or(false, |seg| {
+                seg.ident == "HashSet" || seg.ident == "BTreeSet"
+            })
+    } else {
+        false
+    }
+}
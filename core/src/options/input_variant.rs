@@ -2,7 +2,9 @@ use std::borrow::Cow;
 
 use crate::ast::Fields;
 use crate::codegen;
-use crate::options::{Core, InputField, ParseAttribute};
+use crate::error::Accumulator;
+use crate::options::core::{validate_aliases, validate_warnings_field};
+use crate::options::{Core, InputField, ParseAttribute, UnknownFields};
 use crate::util::{Flag, SpannedValue};
 use crate::{Error, FromMeta, Result};
 
@@ -15,9 +17,12 @@ pub struct InputVariant {
     /// Whether or not the variant should be used to create an instance for
     /// `FromMeta::from_word`.
     pub word: Option<SpannedValue<bool>>,
-    /// Whether or not unknown fields are acceptable in this
-    allow_unknown_fields: Option<bool>,
+    /// How unknown fields are handled in this variant, inherited from the enum.
+    unknown_fields: Option<UnknownFields>,
     pub transparent: Flag,
+    /// Whether or not the variant should receive unrecognized values passed to
+    /// `FromMeta::from_string`.
+    pub other: Flag,
 }
 
 impl InputVariant {
@@ -29,6 +34,10 @@ impl InputVariant {
         self.skip.unwrap_or_default()
     }
 
+    pub fn is_newtype_variant(&self) -> bool {
+        self.data.is_newtype()
+    }
+
     pub fn as_codegen_variant<'a>(&'a self, ty_ident: &'a syn::Ident) -> codegen::Variant<'a> {
         codegen::Variant {
             ty_ident,
@@ -39,8 +48,9 @@ impl InputVariant {
                 .map_or_else(|| Cow::Owned(self.ident.to_string()), Cow::Borrowed),
             data: self.data.as_ref().map(InputField::as_codegen_field),
             skip: self.is_skipped(),
-            allow_unknown_fields: self.allow_unknown_fields.unwrap_or_default(),
+            unknown_fields: self.unknown_fields.unwrap_or_default(),
             transparent: self.transparent.is_present(),
+            other: self.other.is_present(),
         }
     }
 
@@ -51,8 +61,9 @@ impl InputVariant {
             data: Fields::empty_from(&v.fields),
             skip: Default::default(),
             word: Default::default(),
-            allow_unknown_fields: None,
+            unknown_fields: None,
             transparent: Flag::default(),
+            other: Flag::default(),
         })
         .parse_attributes(&v.attrs)?;
 
@@ -83,13 +94,26 @@ impl InputVariant {
         })
     }
 
+    /// Check the fields of a struct variant the same way as those of a struct.
+    pub fn validate_fields(&self, errors: &mut Accumulator) {
+        if self.data.style.is_struct() && !self.is_skipped() {
+            validate_warnings_field(
+                &self.data.fields,
+                self.unknown_fields.unwrap_or_default(),
+                &self.ident,
+                errors,
+            );
+            validate_aliases(&self.data.fields, errors);
+        }
+    }
+
     fn with_inherited(mut self, parent: &Core) -> Self {
         if self.attr_name.is_none() {
             self.attr_name = Some(parent.rename_rule.apply_to_variant(self.ident.to_string()));
         }
 
-        if self.allow_unknown_fields.is_none() {
-            self.allow_unknown_fields = Some(parent.allow_unknown_fields.unwrap_or_default());
+        if self.unknown_fields.is_none() {
+            self.unknown_fields = Some(parent.unknown_fields());
         }
 
         self
@@ -117,6 +141,12 @@ impl ParseAttribute for InputVariant {
             }
 
             self.transparent = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("other") {
+            if self.other.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.other = FromMeta::from_meta(mi)?;
         } else if path.is_ident("word") {
             if self.word.is_some() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
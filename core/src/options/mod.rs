@@ -7,6 +7,7 @@ use crate::util::Callable;
 use crate::{Error, FromMeta, Result};
 
 mod core;
+mod dedup;
 mod forward_attrs;
 mod forwarded_field;
 mod from_attributes;
@@ -19,8 +20,10 @@ mod input_field;
 mod input_variant;
 mod outer_from;
 mod shape;
+mod unknown_fields;
 
 pub use self::core::Core;
+pub use self::dedup::Dedup;
 pub use self::forward_attrs::ForwardAttrsFilter;
 pub use self::forwarded_field::ForwardedField;
 pub use self::from_attributes::FromAttributesOptions;
@@ -33,6 +36,7 @@ pub use self::input_field::InputField;
 pub use self::input_variant::InputVariant;
 pub use self::outer_from::OuterFrom;
 pub use self::shape::{DataShape, DeriveInputShapeSet};
+pub use self::unknown_fields::UnknownFields;
 
 /// A default/fallback expression encountered in attributes during parsing.
 #[derive(Debug, Clone)]
@@ -42,6 +46,9 @@ pub enum DefaultExpression {
     Inherit,
     /// `default = path::to::function` or `default = || default_val()`.
     Explicit(Callable),
+    /// `default_with = path::to::function`, where the function returns `darling::Result<T>`.
+    /// This is not valid in container options.
+    Fallible(Callable),
     Trait {
         /// The input span that is responsible for the use of `Default::default`.
         span: Span,
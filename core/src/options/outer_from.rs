@@ -1,8 +1,8 @@
 use quote::ToTokens;
 use syn::spanned::Spanned;
-use syn::{Field, Meta};
+use syn::{Field, Ident, Meta};
 
-use crate::ast::Data;
+use crate::ast::{Data, NestedMeta};
 use crate::codegen::ForwardAttrs;
 use crate::options::{
     Core, DefaultExpression, ForwardAttrsFilter, ForwardedField, ParseAttribute, ParseData,
@@ -20,6 +20,10 @@ pub struct OuterFrom {
     /// The field on the target struct which should receive the type attributes, if any.
     pub attrs: Option<ForwardedField>,
 
+    /// The field on the target struct, marked `#[darling(attr_span)]`, which should receive
+    /// the span of the first matched attribute, if any.
+    pub attr_span: Option<Ident>,
+
     pub container: Core,
 
     /// The attribute names that should be searched.
@@ -38,6 +42,7 @@ impl OuterFrom {
         Ok(OuterFrom {
             container: Core::start(di)?,
             attrs: Default::default(),
+            attr_span: Default::default(),
             ident: Default::default(),
             attr_names: Default::default(),
             forward_attrs: Default::default(),
@@ -78,6 +83,21 @@ impl ParseAttribute for OuterFrom {
 
 impl ParseData for OuterFrom {
     fn parse_field(&mut self, field: &Field) -> Result<()> {
+        if is_attr_span_field(field)? {
+            if self.attr_span.is_some() {
+                return Err(Error::custom(
+                    "`#[darling(attr_span)]` can only be applied to one field",
+                )
+                .with_span(field));
+            }
+
+            self.attr_span = Some(field.ident.clone().ok_or_else(|| {
+                Error::custom("`#[darling(attr_span)]` field must be a named field")
+                    .with_span(field)
+            })?);
+            return Ok(());
+        }
+
         match field.ident.as_ref().map(|v| v.to_string()).as_deref() {
             Some("ident") => {
                 self.ident = ForwardedField::from_field(field).map(Some)?;
@@ -122,3 +142,39 @@ impl ParseData for OuterFrom {
         };
     }
 }
+
+/// Check whether a field is marked `#[darling(attr_span)]`. Such a field is populated
+/// outside of the normal field-parsing flow, so it accepts no other options.
+fn is_attr_span_field(field: &Field) -> Result<bool> {
+    let mut found = false;
+    let mut others = Vec::new();
+
+    for attr in &field.attrs {
+        if !attr.path().is_ident("darling") {
+            continue;
+        }
+
+        let list = attr.meta.require_list()?;
+        for item in NestedMeta::parse_meta_list(list.tokens.clone())? {
+            match item {
+                NestedMeta::Meta(Meta::Path(ref path)) if path.is_ident("attr_span") => {
+                    found = true;
+                }
+                other => others.push(other),
+            }
+        }
+    }
+
+    if found && !others.is_empty() {
+        let mut errors = Error::accumulator();
+        for other in others {
+            errors.push(
+                Error::custom("`attr_span` cannot be used with other field options")
+                    .with_span(&other),
+            );
+        }
+        errors.finish()?;
+    }
+
+    Ok(found)
+}
@@ -96,12 +96,13 @@ impl ToTokens for DeriveInputShapeSet {
                             if enum_check.is_empty() {
                                 return _darling::export::Err(
                                     _darling::Error::unsupported_shape_with_expected("enum", &format!("struct with {}", struct_check))
+                                        .with_span(&data.enum_token)
                                 );
                             }
 
                             let mut variant_errors = _darling::Error::accumulator();
                             for variant in &data.variants {
-                                variant_errors.handle(enum_check.check(variant));
+                                variant_errors.handle(enum_check.check(variant).map_err(|e| e.with_span(variant)));
                             }
 
                             variant_errors.finish_with(__body)
@@ -110,12 +111,16 @@ impl ToTokens for DeriveInputShapeSet {
                             if struct_check.is_empty() {
                                 return _darling::export::Err(
                                     _darling::Error::unsupported_shape_with_expected("struct", &format!("enum with {}", enum_check))
+                                        .with_span(&struct_data.struct_token)
                                 );
                             }
 
-                            struct_check.check(struct_data).and(_darling::export::Ok(__body))
+                            struct_check
+                                .check(struct_data)
+                                .map_err(|e| e.with_span(&struct_data.struct_token))
+                                .and(_darling::export::Ok(__body))
                         }
-                        _darling::export::syn::Data::Union(_) => {
+                        _darling::export::syn::Data::Union(ref union_data) => {
                             let expected = if enum_check.is_empty() {
                                 format!("struct with {}", struct_check)
                             } else if struct_check.is_empty() {
@@ -123,7 +128,10 @@ impl ToTokens for DeriveInputShapeSet {
                             } else {
                                 format!("struct with {} or enum with {}", struct_check, enum_check)
                             };
-                            _darling::export::Err(_darling::Error::unsupported_shape_with_expected("union", &expected))
+                            _darling::export::Err(
+                                _darling::Error::unsupported_shape_with_expected("union", &expected)
+                                    .with_span(&union_data.union_token)
+                            )
                         },
                     }
                 }
@@ -0,0 +1,43 @@
+use crate::{Error, FromMeta, Result};
+
+/// How a container handles meta items which don't match any of its fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnknownFields {
+    /// Report an error for each unknown field. This is the default.
+    Deny,
+    /// Silently ignore unknown fields.
+    Allow,
+    /// Record a non-fatal warning for each unknown field and continue parsing.
+    Warn,
+}
+
+impl Default for UnknownFields {
+    fn default() -> Self {
+        UnknownFields::Deny
+    }
+}
+
+impl From<bool> for UnknownFields {
+    /// Convert the value of the legacy `allow_unknown_fields` option.
+    fn from(allow: bool) -> Self {
+        if allow {
+            UnknownFields::Allow
+        } else {
+            UnknownFields::Deny
+        }
+    }
+}
+
+impl FromMeta for UnknownFields {
+    fn from_string(value: &str) -> Result<Self> {
+        match value {
+            "deny" => Ok(UnknownFields::Deny),
+            "allow" => Ok(UnknownFields::Allow),
+            "warn" => Ok(UnknownFields::Warn),
+            other => Err(Error::unknown_value_with_alts(
+                other,
+                &["deny", "allow", "warn"],
+            )),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use proc_macro2::Span;
+
+use crate::{Error, Result};
+
+/// Check that exactly one of several mutually exclusive fields was set.
+///
+/// Each entry pairs a field name with the span where it was set, or `None` if it was absent.
+/// If none are set, a single error lists every name. If several are set, each one gets an
+/// error at its own span. This is meant for a container's `and_then` function.
+///
+/// # Example
+/// ```rust,ignore
+/// use darling::{util::{self, Flag, SpannedValue}, FromMeta};
+///
+/// #[derive(FromMeta)]
+/// #[darling(and_then = Self::validate)]
+/// struct Options {
+///     inline: Flag,
+///     path: Option<SpannedValue<String>>,
+/// }
+///
+/// impl Options {
+///     fn validate(self) -> darling::Result<Self> {
+///         util::require_one_of(&[
+///             ("inline", self.inline.is_present().then(|| self.inline.span())),
+///             ("path", self.path.as_ref().map(|p| p.span())),
+///         ])?;
+///         Ok(self)
+///     }
+/// }
+/// ```
+pub fn require_one_of(fields: &[(&str, Option<Span>)]) -> Result<()> {
+    let present = fields
+        .iter()
+        .filter_map(|(name, span)| span.map(|span| (*name, span)))
+        .collect::<Vec<_>>();
+
+    match present.len() {
+        0 => Err(Error::custom(format!(
+            "expected one of {}",
+            join_names(fields.iter().map(|(name, _)| *name), "or")
+        ))),
+        1 => Ok(()),
+        _ => {
+            let message = format!(
+                "{} cannot be used together",
+                join_names(present.iter().map(|(name, _)| *name), "and")
+            );
+            let mut errors = Error::accumulator();
+            for (_, span) in present {
+                errors.push(Error::custom(&message).with_span(&span));
+            }
+            errors.finish()
+        }
+    }
+}
+
+/// Check that a group of fields is either set together or not at all.
+///
+/// Each entry pairs a field name with the span where it was set, or `None` if it was absent.
+/// If only some are set, each missing field gets an error at the span of the first field
+/// which was set.
+pub fn require_together(fields: &[(&str, Option<Span>)]) -> Result<()> {
+    let present = fields
+        .iter()
+        .filter_map(|(name, span)| span.map(|span| (*name, span)))
+        .collect::<Vec<_>>();
+
+    let (_, first_span) = match present.first() {
+        Some(first) if present.len() < fields.len() => *first,
+        _ => return Ok(()),
+    };
+
+    let used = join_names(present.iter().map(|(name, _)| *name), "and");
+    let mut errors = Error::accumulator();
+    for (name, span) in fields {
+        if span.is_none() {
+            errors.push(
+                Error::custom(format!("`{}` is required when using {}", name, used))
+                    .with_span(&first_span),
+            );
+        }
+    }
+
+    errors.finish()
+}
+
+/// Format names as a list such as "`a`, `b`, or `c`".
+fn join_names<'a>(names: impl Iterator<Item = &'a str>, conjunction: &str) -> String {
+    let names = names.map(|name| format!("`{}`", name)).collect::<Vec<_>>();
+    match names.as_slice() {
+        [] => String::new(),
+        [only] => only.clone(),
+        [first, second] => format!("{} {} {}", first, conjunction, second),
+        [init @ .., last] => format!("{}, {} {}", init.join(", "), conjunction, last),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::Span;
+
+    use super::{require_one_of, require_together};
+
+    fn set() -> Option<Span> {
+        Some(Span::call_site())
+    }
+
+    #[test]
+    fn one_of_exactly_one() {
+        require_one_of(&[("a", None), ("b", set())]).unwrap();
+    }
+
+    #[test]
+    fn one_of_none() {
+        let err = require_one_of(&[("a", None), ("b", None), ("c", None)]).unwrap_err();
+        assert_eq!(err.to_string(), "expected one of `a`, `b`, or `c`");
+    }
+
+    #[test]
+    fn one_of_exclusive_violation() {
+        let err = require_one_of(&[("a", set()), ("b", set()), ("c", None)]).unwrap_err();
+        assert_eq!(err.len(), 2);
+        for error in err {
+            assert!(error.has_span());
+            assert_eq!(error.to_string(), "`a` and `b` cannot be used together");
+        }
+    }
+
+    #[test]
+    fn together_all_or_nothing() {
+        require_together(&[("a", set()), ("b", set())]).unwrap();
+        require_together(&[("a", None), ("b", None)]).unwrap();
+    }
+
+    #[test]
+    fn together_missing() {
+        let err = require_together(&[("a", set()), ("b", None), ("c", None)]).unwrap_err();
+        assert_eq!(err.len(), 2);
+        let messages = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            vec![
+                "`b` is required when using `a`",
+                "`c` is required when using `a`"
+            ]
+        );
+    }
+}
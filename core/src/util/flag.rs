@@ -5,13 +5,21 @@ use crate::{FromMeta, Result};
 
 /// A meta-item that can be present as a word - with no value - or absent.
 ///
+/// An explicit boolean value is also accepted, e.g. `foo = false`, which lets callers
+/// turn off a flag that would otherwise be set by a macro-generated attribute.
+///
+/// # Flag vs. `bool`
+/// A `bool` field also accepts the bare word form, so `#[my(verbose)]` sets it to `true`.
+/// The difference is that `Flag` is optional without `#[darling(default)]` and remembers
+/// the span where it was set.
+///
 /// # Defaulting
 /// Like `Option`, `Flag` does not require `#[darling(default)]` to be optional.
 /// If the caller does not include the property, then an absent `Flag` will be included
 /// in the receiver struct.
 ///
 /// # Spans
-/// `Flag` keeps the span where its word was seen.
+/// `Flag` keeps the span where its word or boolean value was seen.
 /// This enables attaching custom error messages to the word, such as in the case of two
 /// conflicting flags being present.
 ///
@@ -42,17 +50,25 @@ use crate::{FromMeta, Result};
 /// //                ^^^^^^ Cannot set flag_a and flag_b
 /// ```
 #[derive(Debug, Clone, Copy, Default)]
-pub struct Flag(Option<Span>);
+pub struct Flag {
+    present: bool,
+    span: Option<Span>,
+}
 
 impl Flag {
     /// Creates a new `Flag` which corresponds to the presence of a value.
     pub fn present() -> Self {
-        Flag(Some(Span::call_site()))
+        Flag {
+            present: true,
+            span: Some(Span::call_site()),
+        }
     }
 
     /// Check if the flag is present.
+    ///
+    /// This is `false` if the flag was absent or explicitly set to `false`.
     pub fn is_present(&self) -> bool {
-        self.0.is_some()
+        self.present
     }
 
     #[deprecated(since = "0.14.0", note = "Use Flag::is_present")]
@@ -60,24 +76,30 @@ impl Flag {
         self.is_present()
     }
 
-    /// Get the span of the flag, or [`Span::call_site`] if the flag was not present.
+    /// Get the span of the flag, or [`Span::call_site`] if the flag was not in the input.
     pub fn span(&self) -> Span {
-        self.0.unwrap_or_else(Span::call_site)
+        self.span.unwrap_or_else(Span::call_site)
     }
 }
 
 impl FromMeta for Flag {
     fn from_none() -> Option<Self> {
-        Some(Flag(None))
+        Some(Flag::default())
     }
 
     fn from_meta(mi: &syn::Meta) -> Result<Self> {
-        if let Meta::Path(p) = mi {
-            Ok(Flag(Some(p.span())))
-        } else {
-            // The implementation for () will produce an error for all non-path meta items;
+        match mi {
+            Meta::Path(p) => Ok(Flag {
+                present: true,
+                span: Some(p.span()),
+            }),
+            Meta::NameValue(nv) => Ok(Flag {
+                present: bool::from_expr(&nv.value).map_err(|e| e.with_span(&nv.value))?,
+                span: Some(nv.value.span()),
+            }),
+            // The implementation for () will produce an error for all list meta items;
             // call it to make sure the span behaviors and error messages are the same.
-            Err(<()>::from_meta(mi).unwrap_err())
+            Meta::List(_) => Err(<()>::from_meta(mi).unwrap_err()),
         }
     }
 }
@@ -93,7 +115,46 @@ impl From<bool> for Flag {
         if v {
             Flag::present()
         } else {
-            Flag(None)
+            Flag::default()
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::Flag;
+    use crate::FromMeta;
+
+    #[test]
+    fn word() {
+        assert!(Flag::from_meta(&parse_quote!(ignore)).unwrap().is_present());
+    }
+
+    #[test]
+    fn explicit_true() {
+        assert!(Flag::from_meta(&parse_quote!(ignore = true))
+            .unwrap()
+            .is_present());
+    }
+
+    #[test]
+    fn explicit_false() {
+        assert!(!Flag::from_meta(&parse_quote!(ignore = false))
+            .unwrap()
+            .is_present());
+    }
+
+    #[test]
+    fn absent() {
+        assert!(!Flag::from_none().unwrap().is_present());
+    }
+
+    #[test]
+    fn rejects_non_bool() {
+        let err = Flag::from_meta(&parse_quote!(ignore = 5)).unwrap_err();
+        assert!(err.has_span());
+        Flag::from_meta(&parse_quote!(ignore(foo))).unwrap_err();
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt;
+
+/// A casing convention for Rust identifiers, used by [`IdentString::to_case`](super::IdentString::to_case).
+///
+/// There is no kebab-case variant, since a `-` cannot appear in an identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// `snake_case`, as used for fields, functions, and modules.
+    Snake,
+    /// `PascalCase`, as used for types and enum variants.
+    Pascal,
+    /// `camelCase`.
+    Camel,
+    /// `SCREAMING_SNAKE_CASE`, as used for constants and statics.
+    ScreamingSnake,
+}
+
+impl Case {
+    /// Convert `value` to this case. Word boundaries are underscores and changes from
+    /// lowercase to uppercase, so the input may be in any of the supported cases.
+    pub(super) fn apply(self, value: &str) -> String {
+        let words = split_words(value);
+        let mut out = String::with_capacity(value.len() + words.len());
+
+        for (i, word) in words.iter().enumerate() {
+            match self {
+                Case::Snake | Case::ScreamingSnake if i > 0 => out.push('_'),
+                _ => {}
+            }
+
+            match self {
+                Case::Snake => out.push_str(&word.to_lowercase()),
+                Case::ScreamingSnake => out.push_str(&word.to_uppercase()),
+                Case::Camel if i == 0 => out.push_str(&word.to_lowercase()),
+                Case::Pascal | Case::Camel => {
+                    let mut chars = word.chars();
+                    if let Some(first) = chars.next() {
+                        out.extend(first.to_uppercase());
+                        out.push_str(&chars.as_str().to_lowercase());
+                    }
+                }
+            }
+        }
+
+        out
+    }
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Case::Snake => "snake_case",
+            Case::Pascal => "PascalCase",
+            Case::Camel => "camelCase",
+            Case::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+        })
+    }
+}
+
+/// Split an identifier into its words, treating a run of capitals followed by a lowercase
+/// letter as an acronym followed by a new word, e.g. `HTTPServer` is `HTTP` and `Server`.
+fn split_words(value: &str) -> Vec<&str> {
+    let mut words = vec![];
+
+    for segment in value.split('_').filter(|s| !s.is_empty()) {
+        let chars = segment.char_indices().collect::<Vec<_>>();
+        let mut start = 0;
+
+        for window in 1..chars.len() {
+            let (idx, ch) = chars[window];
+            let prev = chars[window - 1].1;
+            let next_is_lower = chars
+                .get(window + 1)
+                .map_or(false, |(_, c)| c.is_lowercase());
+
+            if ch.is_uppercase() && (!prev.is_uppercase() || next_is_lower) {
+                words.push(&segment[start..idx]);
+                start = idx;
+            }
+        }
+
+        words.push(&segment[start..]);
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split_words, Case};
+
+    #[test]
+    fn splits_words() {
+        assert_eq!(split_words("MyStruct"), vec!["My", "Struct"]);
+        assert_eq!(split_words("my_struct"), vec!["my", "struct"]);
+        assert_eq!(split_words("HTTPServer"), vec!["HTTP", "Server"]);
+        assert_eq!(split_words("MAX_VALUE"), vec!["MAX", "VALUE"]);
+    }
+
+    #[test]
+    fn applies_case() {
+        assert_eq!(Case::Snake.apply("HTTPServer"), "http_server");
+        assert_eq!(Case::Pascal.apply("max_value"), "MaxValue");
+        assert_eq!(Case::Camel.apply("MAX_VALUE"), "maxValue");
+    }
+}
@@ -7,9 +7,12 @@ use syn::{Ident, Meta};
 
 use crate::{FromMeta, Result};
 
+mod case;
 #[cfg(feature = "serde")]
 mod serde;
 
+pub use self::case::Case;
+
 /// A wrapper for an `Ident` which also keeps the value as a string.
 ///
 /// This struct can be used to perform string comparisons and operations.
@@ -61,6 +64,32 @@ impl IdentString {
     }
 }
 
+impl IdentString {
+    /// Create a new `IdentString` with the same span, converted to `case`.
+    ///
+    /// This is useful for building companion identifiers, such as a `snake_case` function
+    /// named after a `PascalCase` type. If the converted name is a keyword, a raw identifier
+    /// is produced, e.g. `Type` becomes `r#type` in snake case. The keywords `crate`, `self`,
+    /// `super`, and `Self` cannot be raw identifiers, so they get a trailing underscore instead,
+    /// e.g. `Crate` becomes `crate_` in snake case.
+    ///
+    /// # Panics
+    /// This will panic if the converted name is not a valid identifier, such as when the
+    /// original ident consists only of underscores.
+    pub fn to_case(&self, case: Case) -> Self {
+        let name = case.apply(self.string.strip_prefix("r#").unwrap_or(&self.string));
+        let span = self.span();
+
+        if syn::parse_str::<Ident>(&name).is_ok() {
+            Ident::new(&name, span).into()
+        } else if matches!(name.as_str(), "crate" | "self" | "super" | "Self") {
+            Ident::new(&format!("{}_", name), span).into()
+        } else {
+            Ident::new_raw(&name, span).into()
+        }
+    }
+}
+
 impl AsRef<Ident> for IdentString {
     fn as_ref(&self) -> &Ident {
         self.as_ident()
@@ -145,7 +174,7 @@ impl FromMeta for IdentString {
 mod tests {
     use syn::parse_quote;
 
-    use super::IdentString;
+    use super::{Case, IdentString};
 
     #[test]
     fn convert() {
@@ -160,4 +189,41 @@ mod tests {
         assert_eq!(after, "var_my");
         assert_eq!(after, String::from("var_my"));
     }
+
+    #[test]
+    fn to_case() {
+        let i = IdentString::new(parse_quote!(MyStruct));
+        assert_eq!(i.to_case(Case::Snake), "my_struct");
+        assert_eq!(i.to_case(Case::Pascal), "MyStruct");
+        assert_eq!(i.to_case(Case::Camel), "myStruct");
+        assert_eq!(i.to_case(Case::ScreamingSnake), "MY_STRUCT");
+        assert_eq!(
+            format!("{:?}", i.to_case(Case::Snake).span()),
+            format!("{:?}", i.span())
+        );
+    }
+
+    #[test]
+    fn to_case_raw() {
+        let i = IdentString::new(parse_quote!(r#type));
+        assert_eq!(i.to_case(Case::ScreamingSnake), "TYPE");
+
+        let i = IdentString::new(parse_quote!(Type));
+        assert_eq!(i.to_case(Case::Snake), "r#type");
+    }
+
+    #[test]
+    fn to_case_non_raw_keywords() {
+        let i = IdentString::new(parse_quote!(Crate));
+        assert_eq!(i.to_case(Case::Snake), "crate_");
+
+        let i = IdentString::new(parse_quote!(Super));
+        assert_eq!(i.to_case(Case::Camel), "super_");
+
+        let i = IdentString::new(parse_quote!(SELF));
+        assert_eq!(i.to_case(Case::Snake), "self_");
+
+        let i = IdentString::new(parse_quote!(self_));
+        assert_eq!(i.to_case(Case::Pascal), "Self_");
+    }
 }
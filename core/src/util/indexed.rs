@@ -0,0 +1,58 @@
+use std::ops::{Deref, DerefMut};
+
+/// A value and its position within the list it was parsed from.
+///
+/// Parse a field as `Vec<Indexed<T>>` to keep each element's index alongside its value,
+/// which is useful for DSLs where order matters, such as `pipeline("stage_a", "stage_b")`.
+/// Indices count from zero in source order.
+///
+/// # Example
+/// ```rust,ignore
+/// use darling::{util::Indexed, FromMeta};
+///
+/// #[derive(FromMeta)]
+/// struct Options {
+///     pipeline: Vec<Indexed<String>>,
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Indexed<T> {
+    index: usize,
+    value: T,
+}
+
+impl<T> Indexed<T> {
+    pub fn new(index: usize, value: T) -> Self {
+        Indexed { index, value }
+    }
+
+    /// Get the position of this value within its list.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Gets the inner value, consuming `self` in the process.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Indexed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Indexed<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T> AsRef<T> for Indexed<T> {
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
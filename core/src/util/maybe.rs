@@ -0,0 +1,135 @@
+use crate::{FromMeta, Result};
+
+use self::Maybe::*;
+
+/// A value which records whether its field appeared in the input at all.
+///
+/// `Option<T>` also makes a field optional, but when `T` can itself express "nothing",
+/// such as `Option<Option<String>>` or a type with a custom `from_none`, the outer `None`
+/// is easy to confuse with a parsed value. `Maybe` names the two states so they can't
+/// be mixed up.
+///
+/// Like `Option`, `Maybe` does not require `#[darling(default)]` to be optional.
+///
+/// # Example
+/// ```rust,ignore
+/// use darling::{util::Maybe, FromMeta};
+///
+/// #[derive(FromMeta)]
+/// struct Options {
+///     rename: Maybe<Option<String>>,
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Maybe<T> {
+    /// The field was not present in the input.
+    Absent,
+
+    /// The field was present in the input and parsed to this value.
+    Present(T),
+}
+
+impl<T> Maybe<T> {
+    /// Returns `true` if the field was present in the input.
+    pub fn is_present(&self) -> bool {
+        match *self {
+            Absent => false,
+            Present(_) => true,
+        }
+    }
+
+    /// Returns `true` if the field was not present in the input.
+    pub fn is_absent(&self) -> bool {
+        !self.is_present()
+    }
+
+    /// Converts from `Maybe<T>` to `Maybe<&T>`.
+    pub fn as_ref(&self) -> Maybe<&T> {
+        match *self {
+            Absent => Absent,
+            Present(ref val) => Present(val),
+        }
+    }
+
+    /// Converts from `Maybe<T>` to `Option<T>`.
+    pub fn present(self) -> Option<T> {
+        match self {
+            Absent => None,
+            Present(val) => Some(val),
+        }
+    }
+}
+
+impl<T> Default for Maybe<T> {
+    fn default() -> Self {
+        Absent
+    }
+}
+
+impl<T> From<Option<T>> for Maybe<T> {
+    fn from(v: Option<T>) -> Self {
+        match v {
+            None => Absent,
+            Some(val) => Present(val),
+        }
+    }
+}
+
+/// Produces `Maybe::Absent` when the field is missing, and otherwise forwards to `T::from_meta`.
+impl<T: FromMeta> FromMeta for Maybe<T> {
+    fn from_none() -> Option<Self> {
+        Some(Absent)
+    }
+
+    fn from_nested_meta(item: &crate::ast::NestedMeta) -> Result<Self> {
+        T::from_nested_meta(item).map(Present)
+    }
+
+    fn from_meta(item: &syn::Meta) -> Result<Self> {
+        FromMeta::from_meta(item).map(Present)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::Maybe;
+    use crate::FromMeta;
+
+    #[test]
+    fn absent() {
+        assert_eq!(Maybe::<String>::from_none(), Some(Maybe::Absent));
+    }
+
+    #[test]
+    fn present() {
+        assert_eq!(
+            Maybe::<String>::from_meta(&parse_quote!(ignore = "hello")).unwrap(),
+            Maybe::Present("hello".to_string())
+        );
+    }
+
+    #[test]
+    fn present_option() {
+        assert_eq!(
+            Maybe::<Option<u8>>::from_meta(&parse_quote!(ignore = 3)).unwrap(),
+            Maybe::Present(Some(3))
+        );
+    }
+
+    #[test]
+    fn present_literal() {
+        // A bare literal in a list, such as an item for a tuple struct field, reaches
+        // `from_nested_meta` rather than `from_meta`.
+        assert_eq!(
+            Maybe::<u8>::from_nested_meta(&parse_quote!(7)).unwrap(),
+            Maybe::Present(7)
+        );
+    }
+
+    #[test]
+    fn errors_forwarded() {
+        assert!(Maybe::<u8>::from_meta(&parse_quote!(ignore = "abc")).is_err());
+    }
+}
@@ -2,30 +2,50 @@
 
 mod callable;
 pub mod extract_option;
+mod field_constraints;
 mod flag;
 mod ident_string;
 mod ignored;
+mod indexed;
+mod maybe;
+mod one_of;
 mod over_ride;
 mod parse_attribute;
+mod parse_comma_list;
+mod parse_docs;
 pub mod parse_expr;
+mod parsed;
 mod path_list;
 mod path_to_string;
 mod preserved_str_expr;
+mod provenance;
 mod require_ident;
+mod require_path;
 mod shape;
 mod spanned_value;
+mod visibility;
 mod with_original;
 
 pub use self::callable::Callable;
+pub use self::field_constraints::{require_one_of, require_together};
 pub use self::flag::Flag;
-pub use self::ident_string::IdentString;
+pub use self::ident_string::{Case, IdentString};
 pub use self::ignored::Ignored;
+pub use self::indexed::Indexed;
+pub use self::maybe::Maybe;
+pub use self::one_of::{OneOf2, OneOf3};
 pub use self::over_ride::Override;
 pub use self::parse_attribute::parse_attribute_to_meta_list;
+pub use self::parse_comma_list::parse_comma_list;
+pub use self::parse_docs::parse_docs;
+pub use self::parsed::Parsed;
 pub use self::path_list::PathList;
 pub use self::path_to_string::path_to_string;
 pub use self::preserved_str_expr::PreservedStrExpr;
+pub use self::provenance::Provenance;
 pub use self::require_ident::require_ident;
+pub use self::require_path::require_path;
 pub use self::shape::{AsShape, Shape, ShapeSet};
 pub use self::spanned_value::SpannedValue;
+pub use self::visibility::Visibility;
 pub use self::with_original::WithOriginal;
@@ -0,0 +1,125 @@
+use crate::{ast::NestedMeta, Error, FromMeta, Result};
+
+/// Generate an untagged union which parses the first of its types that accepts the input.
+macro_rules! one_of {
+    ($(#[$attr:meta])* $name:ident { $($variant:ident($ty:ident)),+ }) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name<$($ty),+> {
+            $($variant($ty)),+
+        }
+
+        impl<$($ty: FromMeta),+> FromMeta for $name<$($ty),+> {
+            fn from_none() -> Option<Self> {
+                None$(.or_else(|| $ty::from_none().map($name::$variant)))+
+            }
+
+            fn from_nested_meta(item: &NestedMeta) -> Result<Self> {
+                let mut errors = Vec::new();
+                $(
+                    match $ty::from_nested_meta(item) {
+                        Ok(value) => return Ok($name::$variant(value)),
+                        Err(e) => errors.push(e),
+                    }
+                )+
+                Err(Error::multiple(errors).flatten())
+            }
+
+            fn from_meta(item: &syn::Meta) -> Result<Self> {
+                let mut errors = Vec::new();
+                $(
+                    match $ty::from_meta(item) {
+                        Ok(value) => return Ok($name::$variant(value)),
+                        Err(e) => errors.push(e),
+                    }
+                )+
+                Err(Error::multiple(errors).flatten())
+            }
+        }
+    };
+}
+
+one_of!(
+    /// A value which is parsed as `A` if possible, and otherwise as `B`.
+    ///
+    /// This suits fields that accept several forms, such as `source = "file.txt"` or
+    /// `source(url = "...")`. If neither type accepts the input, the error contains the
+    /// errors from both attempts, in order.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use darling::{util::OneOf2, FromMeta};
+    ///
+    /// #[derive(FromMeta)]
+    /// struct Remote {
+    ///     url: String,
+    /// }
+    ///
+    /// #[derive(FromMeta)]
+    /// struct Options {
+    ///     source: OneOf2<String, Remote>,
+    /// }
+    /// ```
+    OneOf2 {
+        First(A),
+        Second(B)
+    }
+);
+
+one_of!(
+    /// A value which is parsed as the first of `A`, `B`, or `C` that accepts the input.
+    ///
+    /// See [`OneOf2`] for details.
+    OneOf3 {
+        First(A),
+        Second(B),
+        Third(C)
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::{OneOf2, OneOf3};
+    use crate::FromMeta;
+
+    #[test]
+    fn first_arm() {
+        assert_eq!(
+            OneOf2::<u8, String>::from_meta(&parse_quote!(ignore = 5)).unwrap(),
+            OneOf2::First(5)
+        );
+    }
+
+    #[test]
+    fn second_arm() {
+        assert_eq!(
+            OneOf2::<u8, String>::from_meta(&parse_quote!(ignore = "five")).unwrap(),
+            OneOf2::Second("five".to_string())
+        );
+    }
+
+    #[test]
+    fn third_arm() {
+        assert_eq!(
+            OneOf3::<u8, bool, String>::from_meta(&parse_quote!(ignore = "x")).unwrap(),
+            OneOf3::Third("x".to_string())
+        );
+    }
+
+    #[test]
+    fn all_arms_fail() {
+        let err = OneOf2::<u8, bool>::from_meta(&parse_quote!(ignore = "x")).unwrap_err();
+        assert_eq!(err.len(), 2);
+    }
+
+    #[test]
+    fn from_none() {
+        assert_eq!(OneOf2::<u8, String>::from_none(), None);
+        assert_eq!(
+            OneOf2::<u8, Option<String>>::from_none(),
+            Some(OneOf2::Second(None))
+        );
+    }
+}
@@ -172,3 +172,20 @@ impl<T: FromMeta> FromMeta for Override<T> {
         Ok(Explicit(FromMeta::from_bool(value)?))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Override;
+
+    #[test]
+    fn unwrap_or_else() {
+        assert_eq!(Override::Inherit.unwrap_or_else(|| 5), 5);
+        assert_eq!(Override::Explicit(3).unwrap_or_else(|| 5), 3);
+    }
+
+    #[test]
+    fn explicit() {
+        assert_eq!(Override::<u8>::Inherit.explicit(), None);
+        assert_eq!(Override::Explicit(3).explicit(), Some(3));
+    }
+}
@@ -0,0 +1,101 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use syn::{Expr, Lit, Meta};
+
+use crate::{Error, Result};
+
+/// Parse a string literal holding a comma-separated list, such as `columns = "a, b, c"`,
+/// into a `Vec`. Use this with `#[darling(with = darling::util::parse_comma_list)]`.
+///
+/// Whitespace around each item is trimmed before it is parsed with [`FromStr`]. Empty
+/// items, including one left by a trailing comma, are errors. All errors point at the
+/// string literal.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromMeta)]
+/// struct Table {
+///     #[darling(with = darling::util::parse_comma_list)]
+///     columns: Vec<String>,
+/// }
+/// ```
+pub fn parse_comma_list<T>(meta: &Meta) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let lit = match meta {
+        Meta::NameValue(nv) => string_literal(&nv.value)?,
+        Meta::Path(_) => return Err(Error::unsupported_format("word").with_span(meta)),
+        Meta::List(_) => return Err(Error::unsupported_format("list").with_span(meta)),
+    };
+
+    let value = lit.value();
+    let mut errors = Error::accumulator();
+    let items = value
+        .split(',')
+        .filter_map(|item| {
+            let item = item.trim();
+            errors.handle(if item.is_empty() {
+                Err(Error::custom("empty item in comma-separated list").with_span(lit))
+            } else {
+                item.parse().map_err(|e| {
+                    Error::custom(format!("invalid item `{}`: {}", item, e)).with_span(lit)
+                })
+            })
+        })
+        .collect();
+
+    errors.finish_with(items)
+}
+
+fn string_literal(expr: &Expr) -> Result<&syn::LitStr> {
+    match expr {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Ok(lit),
+        // See `FromMeta::from_expr` for why invisible groups are unwrapped.
+        Expr::Group(group) => string_literal(&group.expr),
+        _ => Err(Error::unexpected_expr_type(expr).with_span(expr)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::parse_comma_list;
+
+    #[test]
+    fn valid_list() {
+        let items: Vec<String> = parse_comma_list(&parse_quote!(columns = "a,b,c")).unwrap();
+        assert_eq!(items, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn whitespace_trimmed() {
+        let items: Vec<u16> = parse_comma_list(&parse_quote!(ports = " 80 ,443,  8080 ")).unwrap();
+        assert_eq!(items, vec![80, 443, 8080]);
+    }
+
+    #[test]
+    fn trailing_comma_fails() {
+        let err = parse_comma_list::<String>(&parse_quote!(columns = "a, b,")).unwrap_err();
+        assert_eq!(err.to_string(), "empty item in comma-separated list");
+        assert!(err.has_span());
+    }
+
+    #[test]
+    fn bad_items_fail() {
+        let err = parse_comma_list::<u8>(&parse_quote!(ports = "1, x, 300")).unwrap_err();
+        assert_eq!(err.len(), 2);
+        assert!(err.iter().all(|e| e.has_span()));
+    }
+
+    #[test]
+    fn non_string_fails() {
+        parse_comma_list::<String>(&parse_quote!(columns = 5)).unwrap_err();
+        parse_comma_list::<String>(&parse_quote!(columns)).unwrap_err();
+    }
+}
@@ -0,0 +1,159 @@
+use syn::{Attribute, Expr, ExprLit, Lit, Meta};
+
+use crate::{Error, Result};
+
+/// Collects the doc comments in a list of attributes into a single string, so it composes
+/// nicely with `#[darling(with = ...)]` on forwarded attributes.
+///
+/// Each `#[doc = "..."]` attribute contributes one or more lines, which are joined with newlines.
+/// As in rustdoc, the indentation shared by all non-empty lines is removed, and the leading `*`
+/// decoration of block comments (`/** ... */`) is stripped. Attributes other than `doc`, and
+/// `doc` attributes that aren't name-value pairs such as `#[doc(hidden)]`, are ignored. If
+/// there are no doc comments, the result is an empty string.
+///
+/// ```ignore
+/// #[derive(FromDeriveInput)]
+/// #[darling(forward_attrs(doc))]
+/// struct Input {
+///     #[darling(with = darling::util::parse_docs)]
+///     attrs: String,
+/// }
+/// ```
+pub fn parse_docs(attrs: Vec<Attribute>) -> Result<String> {
+    let mut errors = Error::accumulator();
+    let mut lines = Vec::new();
+
+    for attr in &attrs {
+        let value = match &attr.meta {
+            Meta::NameValue(nv) if nv.path.is_ident("doc") => &nv.value,
+            _ => continue,
+        };
+
+        if let Some(doc) = errors.handle(doc_string(value)) {
+            lines.extend(doc_lines(&doc));
+        }
+    }
+
+    errors.finish_with(unindent(lines).join("\n"))
+}
+
+fn doc_string(value: &Expr) -> Result<String> {
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(s), ..
+        }) => Ok(s.value()),
+        _ => Err(Error::custom("expected doc comment to be a string literal").with_span(value)),
+    }
+}
+
+/// Split one doc attribute's value into lines. Values containing newlines come from block
+/// comments, which may have blank first and last lines and a leading `*` on each line.
+fn doc_lines(doc: &str) -> Vec<String> {
+    if !doc.contains('\n') {
+        return vec![doc.trim_end().to_string()];
+    }
+
+    let mut lines: Vec<&str> = doc.lines().collect();
+    if lines.first().map_or(false, |line| line.trim().is_empty()) {
+        lines.remove(0);
+    }
+    if lines.last().map_or(false, |line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    let decorated = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .all(|line| line.trim_start().starts_with('*'));
+
+    lines
+        .into_iter()
+        .map(|line| {
+            let line = if decorated {
+                line.trim_start().strip_prefix('*').unwrap_or(line)
+            } else {
+                line
+            };
+            line.trim_end().to_string()
+        })
+        .collect()
+}
+
+/// Remove the leading whitespace shared by all non-empty lines.
+fn unindent(lines: Vec<String>) -> Vec<String> {
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .into_iter()
+        .map(|line| line.get(indent..).unwrap_or_default().to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse_quote, Attribute, DeriveInput};
+
+    use super::parse_docs;
+
+    fn docs(input: DeriveInput) -> String {
+        parse_docs(input.attrs).unwrap()
+    }
+
+    #[test]
+    fn single_line() {
+        assert_eq!(
+            docs(parse_quote! {
+                /// Hello world
+                struct Demo;
+            }),
+            "Hello world"
+        );
+    }
+
+    #[test]
+    fn multi_line() {
+        assert_eq!(
+            docs(parse_quote! {
+                /// Summary line.
+                ///
+                /// Details:
+                ///   - indented
+                #[allow(dead_code)]
+                struct Demo;
+            }),
+            "Summary line.\n\nDetails:\n  - indented"
+        );
+    }
+
+    #[test]
+    fn block_comment() {
+        let attr: Attribute = parse_quote!(#[doc = "\n * First\n *   Second\n "]);
+        assert_eq!(parse_docs(vec![attr]).unwrap(), "First\n  Second");
+
+        let attr: Attribute = parse_quote!(#[doc = " Inline block "]);
+        assert_eq!(parse_docs(vec![attr]).unwrap(), "Inline block");
+    }
+
+    #[test]
+    fn absent() {
+        assert_eq!(
+            docs(parse_quote! {
+                #[doc(hidden)]
+                #[allow(dead_code)]
+                struct Demo;
+            }),
+            ""
+        );
+    }
+
+    #[test]
+    fn non_literal_errors() {
+        let attr: Attribute = parse_quote!(#[doc = include_str!("README.md")]);
+        parse_docs(vec![attr]).unwrap_err();
+    }
+}
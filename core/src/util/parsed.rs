@@ -0,0 +1,135 @@
+use crate::{ast::NestedMeta, Error, FromMeta, Result};
+
+use self::Parsed::*;
+
+/// A value which holds either the result of parsing `T`, or the error that parsing produced.
+///
+/// Parsing a `Parsed<T>` never fails: an invalid value is stored as `Parsed::Invalid` rather
+/// than failing the whole struct. This lets a macro keep working with the rest of its
+/// options, and report the field's error alongside any others it finds later. The stored
+/// error already points at the bad input and names the field, just as it would have if
+/// parsing had failed.
+///
+/// A missing field is still handled by `T::from_none`, so `Parsed<T>` is only optional if `T` is.
+///
+/// # Example
+/// ```rust,ignore
+/// use darling::{util::Parsed, FromMeta};
+///
+/// #[derive(FromMeta)]
+/// struct Options {
+///     name: String,
+///     limit: Parsed<u8>,
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub enum Parsed<T> {
+    /// The field parsed successfully to this value.
+    Valid(T),
+
+    /// The field was present in the input, but parsing it failed with this error.
+    Invalid(Error),
+}
+
+impl<T> Parsed<T> {
+    /// Returns `true` if the field parsed successfully.
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            Valid(_) => true,
+            Invalid(_) => false,
+        }
+    }
+
+    /// Returns `true` if parsing the field failed.
+    pub fn is_invalid(&self) -> bool {
+        !self.is_valid()
+    }
+
+    /// Converts from `Parsed<T>` to `Result<&T, &Error>`.
+    pub fn as_result(&self) -> ::std::result::Result<&T, &Error> {
+        match *self {
+            Valid(ref val) => Ok(val),
+            Invalid(ref err) => Err(err),
+        }
+    }
+
+    /// Converts from `Parsed<T>` to `darling::Result<T>`.
+    pub fn into_result(self) -> Result<T> {
+        match self {
+            Valid(val) => Ok(val),
+            Invalid(err) => Err(err),
+        }
+    }
+}
+
+impl<T> From<Result<T>> for Parsed<T> {
+    fn from(v: Result<T>) -> Self {
+        match v {
+            Ok(val) => Valid(val),
+            Err(err) => Invalid(err),
+        }
+    }
+}
+
+impl<T> From<Parsed<T>> for Result<T> {
+    fn from(v: Parsed<T>) -> Self {
+        v.into_result()
+    }
+}
+
+/// Stores the outcome of `T::from_meta` without ever returning an error.
+impl<T: FromMeta> FromMeta for Parsed<T> {
+    fn from_none() -> Option<Self> {
+        T::from_none().map(Valid)
+    }
+
+    fn from_nested_meta(item: &NestedMeta) -> Result<Self> {
+        Ok(T::from_nested_meta(item)
+            .map_err(|e| {
+                let e = e.with_span(item);
+                match *item {
+                    NestedMeta::Meta(ref meta) => e.at_path(meta.path()),
+                    NestedMeta::NameValueInvalidExpr(ref nv) => e.at_path(&nv.path),
+                    NestedMeta::Lit(_) => e,
+                }
+            })
+            .into())
+    }
+
+    fn from_meta(item: &syn::Meta) -> Result<Self> {
+        Ok(T::from_meta(item)
+            .map_err(|e| e.with_span(item).at_path(item.path()))
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::Parsed;
+    use crate::FromMeta;
+
+    #[test]
+    fn valid() {
+        let parsed = Parsed::<u8>::from_meta(&parse_quote!(ignore = 3)).unwrap();
+        assert!(parsed.is_valid());
+        assert_eq!(parsed.into_result().unwrap(), 3);
+    }
+
+    #[test]
+    fn invalid() {
+        let parsed = Parsed::<u8>::from_meta(&parse_quote!(ignore = "abc")).unwrap();
+        assert!(parsed.is_invalid());
+
+        let err = parsed.into_result().unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(err.location(), vec!["ignore"]);
+    }
+
+    #[test]
+    fn from_none() {
+        assert!(Parsed::<u8>::from_none().is_none());
+        assert!(Parsed::<Option<u8>>::from_none().unwrap().is_valid());
+    }
+}
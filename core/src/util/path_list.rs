@@ -18,6 +18,9 @@ use super::path_to_string;
 ///     derive: PathList(vec![syn::Path::new("serde::Debug"), syn::Path::new("Clone")])
 /// }
 /// ```
+///
+/// A single path can also be given as a value, either bare or quoted; `#[builder(derive = Clone)]`
+/// and `#[builder(derive = "Clone")]` both produce the same one-element list as `#[builder(derive(Clone))]`.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct PathList(Vec<Path>);
 
@@ -74,6 +77,10 @@ impl FromMeta for PathList {
 
         Ok(PathList(paths))
     }
+
+    fn from_expr(expr: &syn::Expr) -> Result<Self> {
+        Path::from_expr(expr).map(|path| PathList(vec![path]))
+    }
 }
 
 #[cfg(test)]
@@ -108,6 +115,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn single_path_forms_are_equivalent() {
+        let list = fm::<PathList>(quote!(ignore(serde::Serialize)));
+        assert_eq!(list, fm::<PathList>(quote!(ignore = serde::Serialize)));
+        assert_eq!(list, fm::<PathList>(quote!(ignore = "serde::Serialize")));
+        assert_eq!(list.to_strings(), vec![String::from("serde::Serialize")]);
+    }
+
+    #[test]
+    fn fails_non_path_value() {
+        let err = PathList::from_meta(&pm(quote!(ignore = 5)).unwrap()).unwrap_err();
+        assert!(err.has_span());
+    }
+
     /// Check that the parser rejects non-word members of the list, and that the error
     /// has an associated span.
     #[test]
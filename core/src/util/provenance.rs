@@ -0,0 +1,140 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::{FromMeta, Result};
+
+/// A value which records whether it was parsed from the input or filled in by a default.
+///
+/// This lets a macro treat a defaulted field differently from one the user wrote, for
+/// example to warn when a user relies on a default which is due to change.
+///
+/// When the field is missing, the value comes from `Default::default()` with
+/// `#[darling(default)]`, or from `T::from_none()` otherwise. Either way it is marked as
+/// defaulted. A custom default should build its value with [`Provenance::defaulted`].
+///
+/// # Example
+/// ```rust,ignore
+/// use darling::{util::Provenance, FromMeta};
+///
+/// #[derive(FromMeta)]
+/// struct Options {
+///     #[darling(default)]
+///     level: Provenance<u8>,
+///     #[darling(default = || Provenance::defaulted("info".to_string()))]
+///     target: Provenance<String>,
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Provenance<T> {
+    value: T,
+    explicit: bool,
+}
+
+impl<T> Provenance<T> {
+    /// Create a value which was provided in the input.
+    pub fn explicit(value: T) -> Self {
+        Provenance {
+            value,
+            explicit: true,
+        }
+    }
+
+    /// Create a value which was filled in by a default.
+    pub fn defaulted(value: T) -> Self {
+        Provenance {
+            value,
+            explicit: false,
+        }
+    }
+
+    /// Returns `true` if the value was provided in the input.
+    pub fn is_explicit(&self) -> bool {
+        self.explicit
+    }
+
+    /// Returns `true` if the value was filled in by a default.
+    pub fn is_default(&self) -> bool {
+        !self.explicit
+    }
+
+    /// Gets the inner value, consuming `self` in the process.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Default> Default for Provenance<T> {
+    fn default() -> Self {
+        Provenance::defaulted(T::default())
+    }
+}
+
+impl<T> Deref for Provenance<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Provenance<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T> AsRef<T> for Provenance<T> {
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// Marks values parsed by `T` as explicit, and the fallback from `T::from_none` as defaulted.
+impl<T: FromMeta> FromMeta for Provenance<T> {
+    fn from_none() -> Option<Self> {
+        T::from_none().map(Provenance::defaulted)
+    }
+
+    fn from_nested_meta(item: &crate::ast::NestedMeta) -> Result<Self> {
+        T::from_nested_meta(item).map(Provenance::explicit)
+    }
+
+    fn from_meta(item: &syn::Meta) -> Result<Self> {
+        T::from_meta(item).map(Provenance::explicit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::Provenance;
+    use crate::FromMeta;
+
+    #[test]
+    fn explicit() {
+        let value = Provenance::<u8>::from_meta(&parse_quote!(ignore = 3)).unwrap();
+        assert!(value.is_explicit());
+        assert_eq!(*value, 3);
+    }
+
+    #[test]
+    fn default_is_defaulted() {
+        let value = Provenance::<u8>::default();
+        assert!(value.is_default());
+        assert_eq!(value.into_inner(), 0);
+    }
+
+    #[test]
+    fn from_none() {
+        assert_eq!(Provenance::<u8>::from_none(), None);
+        assert_eq!(
+            Provenance::<Option<u8>>::from_none(),
+            Some(Provenance::defaulted(None))
+        );
+    }
+
+    #[test]
+    fn errors_forwarded() {
+        assert!(Provenance::<u8>::from_meta(&parse_quote!(ignore = "abc")).is_err());
+    }
+}
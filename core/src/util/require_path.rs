@@ -0,0 +1,45 @@
+use crate::Error;
+use syn::Path;
+
+/// Attaches error message to `Option<Path>`, turning it into `darling::Result<Path>`,
+/// so it composes nicely with `#[darling(with = ...)]`.
+///
+/// This is the [`syn::Path`] counterpart to [`require_ident`](crate::util::require_ident).
+///
+/// ```rust
+/// # extern crate darling_core as darling;
+/// # extern crate syn;
+/// use darling::util::require_path;
+/// use syn::parse_quote;
+///
+/// let path: syn::Path = parse_quote!(std::mem::replace);
+/// assert_eq!(require_path(Some(path.clone())).unwrap(), path);
+///
+/// let err = require_path(None).unwrap_err();
+/// assert_eq!(err.to_string(), "expected path");
+/// ```
+///
+/// When a field receives an optional path, this lets the receiving struct store the
+/// path directly:
+///
+/// ```ignore
+/// #[derive(FromMeta)]
+/// struct Options {
+///     // Without #[darling(with)], this would need to be `handler: Option<syn::Path>`
+///     #[darling(with = |meta| darling::util::require_path(FromMeta::from_meta(meta)?))]
+///     handler: syn::Path,
+/// }
+/// ```
+pub fn require_path(path: Option<Path>) -> crate::Result<Path> {
+    path.ok_or_else(|| Error::custom("expected path"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::require_path;
+
+    #[test]
+    fn none_errors() {
+        assert_eq!(require_path(None).unwrap_err().to_string(), "expected path");
+    }
+}
@@ -104,7 +104,7 @@ impl AsShape for Shape {
 /// // This is correct, because all newtypes are single-field tuples.
 /// assert!(shape_set.contains(&Shape::Newtype));
 /// ```
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct ShapeSet {
     newtype: bool,
     named: bool,
@@ -158,6 +158,28 @@ impl ShapeSet {
         !self.named && !self.newtype && !self.tuple && !self.unit
     }
 
+    /// Iterate over the shapes which were inserted into this set.
+    ///
+    /// Unlike [`contains`](ShapeSet#method.contains), this does not report
+    /// [`Shape::Newtype`] unless it was inserted directly.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use darling_core::util::{Shape, ShapeSet};
+    /// let shape_set = ShapeSet::new(vec![Shape::Unit, Shape::Named]);
+    /// assert_eq!(shape_set.iter().collect::<Vec<_>>(), vec![Shape::Named, Shape::Unit]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Shape> {
+        [
+            (self.named, Shape::Named),
+            (self.tuple, Shape::Tuple),
+            (self.newtype, Shape::Newtype),
+            (self.unit, Shape::Unit),
+        ]
+        .into_iter()
+        .filter_map(|(included, shape)| if included { Some(shape) } else { None })
+    }
+
     fn contains_shape(&self, shape: Shape) -> bool {
         match shape {
             Shape::Named => self.named,
@@ -269,6 +291,21 @@ mod tests {
         };
     }
 
+    #[test]
+    fn query_shapes() {
+        let filter = ShapeSet::new(vec![Shape::Named, Shape::Newtype]);
+        assert!(filter.contains(&Shape::Named));
+        assert!(filter.contains(&Shape::Newtype));
+        assert!(!filter.contains(&Shape::Tuple));
+        assert!(!filter.contains(&Shape::Unit));
+        assert_eq!(
+            filter.iter().collect::<Vec<_>>(),
+            vec![Shape::Named, Shape::Newtype]
+        );
+        assert_eq!(filter.clone(), filter);
+        assert!(ShapeSet::default().iter().next().is_none());
+    }
+
     #[test]
     fn newtype_rejects_tuple() {
         let filter = ShapeSet::new(vec![Shape::Newtype]);
@@ -35,6 +35,11 @@ impl<T> SpannedValue<T> {
         self.span
     }
 
+    /// Apply a mapping function to the spanned value, preserving its span.
+    pub fn map<U>(self, map_fn: impl FnOnce(T) -> U) -> SpannedValue<U> {
+        SpannedValue::new(map_fn(self.value), self.span)
+    }
+
     /// Apply a mapping function to a reference to the spanned value.
     pub fn map_ref<U>(&self, map_fn: impl FnOnce(&T) -> U) -> SpannedValue<U> {
         SpannedValue::new(map_fn(&self.value), self.span)
@@ -147,4 +152,19 @@ mod tests {
         let test = SpannedValue::new("hello", Span::call_site());
         assert_eq!("hello", test.trim());
     }
+
+    #[test]
+    fn deref_mut() {
+        let mut test = SpannedValue::new(String::from("hello"), Span::call_site());
+        test.push_str(" world");
+        assert_eq!("hello world", test.as_str());
+    }
+
+    #[test]
+    fn map_preserves_span() {
+        let span = Span::mixed_site();
+        let test = SpannedValue::new("42", span).map(|v| v.parse::<u8>().unwrap());
+        assert_eq!(*test, 42);
+        assert_eq!(format!("{:?}", test.span()), format!("{:?}", span));
+    }
 }
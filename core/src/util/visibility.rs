@@ -0,0 +1,155 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Expr, Meta};
+
+use crate::{FromMeta, Result};
+
+/// A visibility with the restricted path pre-extracted, for easier matching than
+/// [`syn::Visibility`].
+///
+/// This can be used as the type of the forwarded `vis` field when deriving
+/// `FromDeriveInput` or `FromField`, and can also be parsed from a meta item
+/// such as `vis = "pub(crate)"`.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromField)]
+/// struct Field {
+///     vis: darling::util::Visibility,
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Visibility {
+    /// `pub`
+    Public,
+    /// `pub(crate)` or `pub(in crate)`
+    Crate,
+    /// `pub(self)`, `pub(super)`, or `pub(in some::path)`; this holds the path.
+    Restricted(syn::Path),
+    /// No visibility modifier.
+    Inherited,
+}
+
+impl Visibility {
+    /// Returns `true` if this is `pub`.
+    pub fn is_public(&self) -> bool {
+        matches!(self, Visibility::Public)
+    }
+
+    /// Returns `true` if there is no visibility modifier.
+    pub fn is_inherited(&self) -> bool {
+        matches!(self, Visibility::Inherited)
+    }
+}
+
+impl From<syn::Visibility> for Visibility {
+    fn from(vis: syn::Visibility) -> Self {
+        match vis {
+            syn::Visibility::Public(_) => Visibility::Public,
+            syn::Visibility::Restricted(restricted) => {
+                if restricted.path.is_ident("crate") {
+                    Visibility::Crate
+                } else {
+                    Visibility::Restricted(*restricted.path)
+                }
+            }
+            syn::Visibility::Inherited => Visibility::Inherited,
+        }
+    }
+}
+
+impl FromMeta for Visibility {
+    fn from_meta(item: &Meta) -> Result<Self> {
+        syn::Visibility::from_meta(item).map(Self::from)
+    }
+
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        syn::Visibility::from_expr(expr).map(Self::from)
+    }
+
+    fn from_string(value: &str) -> Result<Self> {
+        syn::Visibility::from_string(value).map(Self::from)
+    }
+}
+
+impl ToTokens for Visibility {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Visibility::Public => quote!(pub),
+            Visibility::Crate => quote!(pub(crate)),
+            Visibility::Restricted(path) => {
+                if path.is_ident("self") || path.is_ident("super") {
+                    quote!(pub(#path))
+                } else {
+                    quote!(pub(in #path))
+                }
+            }
+            Visibility::Inherited => quote!(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    use super::Visibility;
+    use crate::FromMeta;
+
+    fn vis(v: syn::Visibility) -> Visibility {
+        v.into()
+    }
+
+    #[test]
+    fn public() {
+        assert_eq!(vis(parse_quote!(pub)), Visibility::Public);
+    }
+
+    #[test]
+    fn crate_() {
+        assert_eq!(vis(parse_quote!(pub(crate))), Visibility::Crate);
+        assert_eq!(vis(parse_quote!(pub(in crate))), Visibility::Crate);
+    }
+
+    #[test]
+    fn restricted() {
+        assert_eq!(
+            vis(parse_quote!(pub(in foo::bar))),
+            Visibility::Restricted(parse_quote!(foo::bar))
+        );
+        assert_eq!(
+            vis(parse_quote!(pub(super))),
+            Visibility::Restricted(parse_quote!(super))
+        );
+    }
+
+    #[test]
+    fn inherited() {
+        assert_eq!(vis(syn::Visibility::Inherited), Visibility::Inherited);
+    }
+
+    #[test]
+    fn round_trip() {
+        for input in [
+            quote::quote!(pub),
+            quote::quote!(pub(crate)),
+            quote::quote!(pub(super)),
+            quote::quote!(pub(in foo::bar)),
+        ] {
+            let parsed: syn::Visibility = syn::parse2(input.clone()).unwrap();
+            assert_eq!(
+                Visibility::from(parsed).into_token_stream().to_string(),
+                input.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn from_meta() {
+        assert_eq!(
+            Visibility::from_meta(&parse_quote!(vis = "pub(crate)")).unwrap(),
+            Visibility::Crate
+        );
+    }
+}
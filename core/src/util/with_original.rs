@@ -1,3 +1,6 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+
 use crate::{
     usage::{UsesLifetimes, UsesTypeParams},
     FromDeriveInput, FromField, FromGenericParam, FromGenerics, FromMeta, FromTypeParam,
@@ -5,6 +8,9 @@ use crate::{
 };
 
 /// A container to parse some syntax and retain access to the original.
+///
+/// The original can be kept either as the `syn` type that was parsed, or as a
+/// [`proc_macro2::TokenStream`] for callers that want to re-emit the untouched input.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WithOriginal<T, O> {
     pub parsed: T,
@@ -35,6 +41,27 @@ with_original!(FromMeta, from_meta, syn::Meta);
 with_original!(FromTypeParam, from_type_param, syn::TypeParam);
 with_original!(FromVariant, from_variant, syn::Variant);
 
+macro_rules! with_original_tokens {
+    ($trayt:ident, $func:ident, $syn:path) => {
+        impl<T: $trayt> $trayt for WithOriginal<T, TokenStream> {
+            fn $func(value: &$syn) -> Result<Self> {
+                Ok(WithOriginal::new(
+                    $trayt::$func(value)?,
+                    value.to_token_stream(),
+                ))
+            }
+        }
+    };
+}
+
+with_original_tokens!(FromDeriveInput, from_derive_input, syn::DeriveInput);
+with_original_tokens!(FromField, from_field, syn::Field);
+with_original_tokens!(FromGenerics, from_generics, syn::Generics);
+with_original_tokens!(FromGenericParam, from_generic_param, syn::GenericParam);
+with_original_tokens!(FromMeta, from_meta, syn::Meta);
+with_original_tokens!(FromTypeParam, from_type_param, syn::TypeParam);
+with_original_tokens!(FromVariant, from_variant, syn::Variant);
+
 /// Get the lifetime usage of `parsed`.
 impl<P: UsesLifetimes, O> UsesLifetimes for WithOriginal<P, O> {
     fn uses_lifetimes<'a>(
@@ -56,3 +83,36 @@ impl<P: UsesTypeParams, O> UsesTypeParams for WithOriginal<P, O> {
         self.parsed.uses_type_params(options, type_set)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::TokenStream;
+    use syn::parse_quote;
+
+    use super::WithOriginal;
+    use crate::FromMeta;
+
+    #[test]
+    fn original_tokens() {
+        let attr: syn::Attribute = parse_quote!(#[ignore(hello = "world", there)]);
+        let parsed =
+            WithOriginal::<std::collections::HashMap<String, String>, TokenStream>::from_meta(
+                &syn::Meta::List(parse_quote!(ignore(hello = "world"))),
+            )
+            .unwrap();
+
+        assert_eq!(parsed.parsed["hello"], "world");
+        assert_eq!(
+            parsed.original.to_string(),
+            quote::quote!(ignore(hello = "world")).to_string()
+        );
+
+        let reparsed: syn::Meta = syn::parse2(
+            WithOriginal::<syn::Meta, TokenStream>::from_meta(&attr.meta)
+                .unwrap()
+                .original,
+        )
+        .unwrap();
+        assert_eq!(reparsed, attr.meta);
+    }
+}
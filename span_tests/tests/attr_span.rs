@@ -0,0 +1,87 @@
+//! Tests for `#[darling(attr_span)]`, which captures the span of the matched attribute.
+
+use darling::{FromDeriveInput, FromField};
+use proc_macro2::Span;
+use syn::{spanned::Spanned, DeriveInput};
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(my))]
+struct Receiver {
+    #[darling(attr_span)]
+    span: Span,
+    #[darling(default)]
+    name: Option<String>,
+}
+
+#[derive(FromField)]
+#[darling(attributes(my))]
+struct FieldReceiver {
+    #[darling(attr_span)]
+    span: Span,
+}
+
+fn assert_same_span(actual: Span, expected: Span) {
+    assert_eq!(actual.start(), expected.start());
+    assert_eq!(actual.end(), expected.end());
+}
+
+#[test]
+fn span_of_attribute() {
+    let di: DeriveInput = syn::parse_str(
+        r#"
+        #[derive(Foo)]
+        #[my(name = "hello")]
+        struct Example;
+        "#,
+    )
+    .unwrap();
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name.as_deref(), Some("hello"));
+    assert_same_span(receiver.span, di.attrs[1].span());
+}
+
+#[test]
+fn first_of_several_attributes() {
+    let di: DeriveInput = syn::parse_str(
+        r#"
+        #[my()]
+        #[my(name = "hello")]
+        struct Example;
+        "#,
+    )
+    .unwrap();
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_same_span(receiver.span, di.attrs[0].span());
+}
+
+#[test]
+fn span_of_item_without_attribute() {
+    let di: DeriveInput = syn::parse_str("struct Example;").unwrap();
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name, None);
+    assert_same_span(receiver.span, di.span());
+}
+
+#[test]
+fn span_of_field_attribute() {
+    let di: DeriveInput = syn::parse_str(
+        r#"
+        struct Example {
+            #[my]
+            inner: u8,
+        }
+        "#,
+    )
+    .unwrap();
+
+    let field = match di.data {
+        syn::Data::Struct(data) => data.fields.into_iter().next().unwrap(),
+        _ => unreachable!(),
+    };
+
+    let receiver = FieldReceiver::from_field(&field).unwrap();
+    assert_same_span(receiver.span, field.attrs[0].span());
+}
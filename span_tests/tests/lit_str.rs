@@ -0,0 +1,25 @@
+//! `syn::LitStr` fields keep the span of the literal they were read from.
+
+use darling::{FromDeriveInput, FromMeta};
+
+#[derive(FromMeta)]
+struct Doc {
+    text: syn::LitStr,
+}
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(doc_it))]
+struct Receiver {
+    doc: Doc,
+}
+
+#[test]
+fn keeps_span() {
+    let di: syn::DeriveInput =
+        syn::parse_str("#[doc_it(doc(text = \"hello\"_ext))]\nstruct Foo;").unwrap();
+    let text = Receiver::from_derive_input(&di).unwrap().doc.text;
+
+    let (start, end) = (text.span().start(), text.span().end());
+    assert_eq!((start.line, start.column), (1, 20));
+    assert_eq!((end.line, end.column), (1, 31));
+}
@@ -0,0 +1,50 @@
+//! Elements of `Vec<SpannedValue<T>>` keep their own spans.
+
+use darling::{util::SpannedValue, FromAttributes};
+use syn::{parse::Parser, Attribute};
+
+#[derive(FromAttributes)]
+#[darling(attributes(tags))]
+struct SpannedElements {
+    #[darling(default)]
+    list: Vec<SpannedValue<String>>,
+    #[darling(multiple)]
+    each: Vec<SpannedValue<String>>,
+}
+
+fn columns(values: &[SpannedValue<String>]) -> Vec<(String, usize)> {
+    values
+        .iter()
+        .map(|v| (v.as_str().to_string(), v.span().start().column))
+        .collect()
+}
+
+#[test]
+fn vec_elements_keep_their_spans() {
+    let attrs = Attribute::parse_outer
+        .parse_str(r#"#[tags(list("a", "bc"), each = "d", each = "e")]"#)
+        .unwrap();
+    let parsed = SpannedElements::from_attributes(&attrs).unwrap();
+
+    assert_eq!(
+        columns(&parsed.list),
+        vec![("a".to_string(), 12), ("bc".to_string(), 17)]
+    );
+    assert_eq!(
+        columns(&parsed.each),
+        vec![("d".to_string(), 31), ("e".to_string(), 43)]
+    );
+}
+
+#[test]
+fn vec_elements_from_array() {
+    let attrs = Attribute::parse_outer
+        .parse_str(r#"#[tags(list = ["a", "b"])]"#)
+        .unwrap();
+    let parsed = SpannedElements::from_attributes(&attrs).unwrap();
+
+    assert_eq!(
+        columns(&parsed.list),
+        vec![("a".to_string(), 15), ("b".to_string(), 20)]
+    );
+}
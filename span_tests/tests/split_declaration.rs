@@ -0,0 +1,34 @@
+//! Duplicate fields split across attributes are reported at each occurrence.
+
+use darling::FromDeriveInput;
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(split))]
+#[allow(dead_code)]
+struct Lorem {
+    foo: String,
+    bar: bool,
+}
+
+#[test]
+fn duplicate_reports_both_occurrences() {
+    let di: syn::DeriveInput = syn::parse_str(
+        r#"
+        #[split(foo = "Hello", bar)]
+        #[split(foo = "World")]
+        pub struct Foo;
+        "#,
+    )
+    .unwrap();
+
+    let spans: Vec<_> = Lorem::from_derive_input(&di)
+        .unwrap_err()
+        .into_iter()
+        .map(|e| e.span().start())
+        .collect();
+
+    assert_eq!(spans.len(), 2);
+    assert_ne!(spans[0], spans[1]);
+    assert_eq!(spans[0].line, 2);
+    assert_eq!(spans[1].line, 3);
+}
@@ -17,12 +17,54 @@
 //!
 //! * **Field renaming**: You can use `#[darling(rename="new_name")]` on a field to change the name Darling looks for.
 //!   You can also use `#[darling(rename_all="...")]` at the struct or enum level to apply a casing rule to all fields or variants.
+//!   The supported rules are `lowercase`, `snake_case`, `kebab-case`, `camelCase`, `PascalCase`, and `SCREAMING_SNAKE_CASE`.
+//!   Explicit `rename` on a field or variant takes precedence over the container rule. Because attribute keys must be paths,
+//!   `kebab-case` is only useful for multi-word names that appear as values, such as unit enum variants.
+//! * **Field aliases**: Use `#[darling(alias = "other_name")]` on a field to accept additional names for it, such as alternate
+//!   spellings. To keep accepting a previous name while steering users away from it, use `#[darling(deprecated_alias = "old_name")]`
+//!   instead; using it records a deprecation warning in the container's `warnings` field. Both options can be repeated,
+//!   but an alias can't repeat its own field's name or any name accepted by another field.
+//! * **Catch-all fields**: You can use `#[darling(rest)]` on one field to collect every unknown meta item instead of
+//!   producing an error. The field type must implement `FromIterator<(String, syn::Meta)>`, such as `HashMap<String, syn::Meta>`.
+//!   Each unknown name may appear only once; a repeat is reported as a duplicate field.
+//! * **Unknown fields**: Use `#[darling(unknown_fields = "deny" | "allow" | "warn")]` on a struct or enum to choose how
+//!   unrecognized meta items are handled; the default is `"deny"`. With `"warn"`, parsing succeeds and each unknown field is
+//!   recorded as a non-fatal warning in the container's `warnings` field. A `flatten`ed sub-struct receives
+//!   its parent's unknown items, so its own setting takes precedence for them.
+//! * **Warnings**: Use `#[darling(warnings)]` on one field of type `Vec<darling::Error>` to receive the non-fatal warnings
+//!   recorded while parsing its container. It is required when the container uses `unknown_fields = "warn"` or a
+//!   `deprecated_alias`, so warnings are never dropped. Warnings from a nested struct are stored in that struct's own field.
+//!   Proc-macro authors decide how to surface them to the user.
+//! * **Custom parsing**: Use `#[darling(with = path::to::function)]` to parse a field with a function of type
+//!   `fn(&syn::Meta) -> darling::Result<T>`. It receives the entire meta item, including its path, so it can inspect
+//!   which name or alias was used. Errors it returns are given the field's name and span, just like built-in parsing,
+//!   unless the function already set a more specific span.
 //! * **Map function**: You can use `#[darling(map="path::to::function")]` to run code on a field before it's stored in the struct.
+//! * **Field validation**: You can use `#[darling(and_then = path::to::function)]` on a field to validate it after parsing.
+//!   The function takes the value and returns `darling::Result<T>`; it runs after `with` and `map`, and errors are reported at the field.
+//! * **Struct validation**: Use `#[darling(and_then = path::to::function)]` on the container for checks that span several fields.
+//!   The function takes `Self` and returns `darling::Result<Self>`, so it sees every parsed field with its declared type. It runs
+//!   only once all fields have parsed; use [`Error::accumulator`] inside it to report several problems at once. When the struct
+//!   is nested in another, its errors are combined with the parent's other errors. `map` and `and_then` cannot both be set on a container.
 //! * **Default values**: You can use `#[darling(default)]` at the type or field level to use that type's default value to fill
 //!   in values not specified by the caller. You can also set a custom default value by passing in a function path or a closure:
 //!   `#[darling(default = path::to::function)]` or `#[darling(default = || get_default())]`.
+//!   If computing the default can fail, use `#[darling(default_with = path::to::function)]` on the field instead;
+//!   the function returns `darling::Result<T>` and any error is reported alongside the other parsing errors.
+//!   To read a missing field from an environment variable while the macro expands, use `#[darling(default_env = "VAR")]`.
+//!   The variable's value is parsed with `FromMeta::from_string`; if it is unset, any `default` or `default_with` is used,
+//!   and otherwise a missing field error names the variable. Cargo doesn't know the expansion read the variable, so
+//!   changing it won't rebuild the crate using your macro; to track it, have your macro emit a use of it such as
+//!   `const _: Option<&str> = option_env!("VAR");` in its output.
+//! * **Non-empty collections**: Use `#[darling(deny_empty)]` on a collection field to reject an explicitly empty list or
+//!   array such as `foo()` or `foo = []`. An absent field still falls back to its default or produces a missing field error.
 //! * **Skipped fields**: You can skip a variant or field using `#[darling(skip)]`. Fields marked with this will fall back to
 //!   `Default::default()` for their value, but you can override that with an explicit default or a value from the type-level default.
+//!   An explicit default such as `#[darling(skip, default = path::to::function)]` doesn't require the field's type to implement
+//!   `Default`. To compute a skipped field from the other fields, set it in the container's `and_then` function.
+//!   To decide at parse time instead, use `#[darling(skip_if = path::to::predicate)]` with a `fn() -> bool`. When the predicate
+//!   returns `true`, the field falls back to its default and setting it in the input is an error; otherwise it is parsed as usual.
+//!   Without an explicit or type-level default, a skipped field uses its type's `FromMeta::from_none` value, then `Default::default()`.
 //! * **Custom shorthand**: Use `#[darling(from_word = ...)]` on a struct or enum to override how a simple word is interpreted.
 //!   By default, it is an error for your macro's user to fail to specify the fields of your struct, but with this you can choose to
 //!   instead produce a set of default values. This takes either a path or a closure whose signature matches `FromMeta::from_word`.
@@ -31,6 +73,22 @@
 //!   value, so a missing field error is generated. `Option<T: FromMeta>` uses this to make options optional without requiring
 //!   `#[darling(default)]` declarations, and structs and enums can use this themselves with `#[darling(from_none = ...)]`.
 //!   This takes either a path or a closure whose signature matches `FromMeta::from_none`.
+//!   To replace the text of that error for one field, use `#[darling(missing_message = "...")]`.
+//! * **String shorthand**: Use `#[darling(from_string = ...)]` on a struct to also accept a single string literal, such as
+//!   `size = "100x200"` or `size("100x200")`, in place of the structured form. This takes either a path or a closure whose
+//!   signature matches `FromMeta::from_string`.
+//! * **Fallback enum variant**: Add `#[darling(other)]` to one newtype variant of a `FromMeta` enum, such as `Other(String)`,
+//!   to have it receive any string value which doesn't match a known variant instead of producing an error.
+//! * **Internally-tagged enums**: Add `#[darling(tag = "kind")]` to a `FromMeta` enum to select the variant from a named item,
+//!   e.g. `shape(kind = "circle", radius = 2)`. The other items populate the chosen variant's fields, and an unknown tag value
+//!   lists the known variants.
+//! * **Positional fields**: Deriving `FromMeta` on a tuple struct reads its fields in order, e.g. `range(0, 100)`.
+//!   On a struct with named fields, mark the leading fields `#[darling(positional)]` to read them from the front of the list
+//!   before any named items, e.g. `route("/path", method = "GET")`. Only literals are read positionally, so a bare word
+//!   such as a flag is always a named item. A missing positional value is reported as a missing field.
+//! * **Attribute span**: Mark a `proc_macro2::Span` field `#[darling(attr_span)]` on a struct deriving `FromDeriveInput`,
+//!   `FromField`, `FromVariant`, or `FromTypeParam` to capture the span of the first matched attribute, or of the input item if
+//!   no attribute matched. This is useful for pointing later errors at the attribute.
 //! * **Generate [`syn::parse::Parse`] impl**: When deriving `FromMeta`, add `#[darling(derive_syn_parse)]` to also generate an impl
 //!   of the `Parse` trait.
 //! * **Renamed crate**: You can use `#[darling(crate = ...)]` to specify a path to the `darling` crate
@@ -51,7 +109,7 @@
 //! |Field name|Type|Meaning|
 //! |---|---|---|
 //! |`ident`|`syn::Ident` (or anything, using `#[darling(with = ...)]`)|The identifier of the passed-in type|
-//! |`vis`|`syn::Visibility`|The visibility of the passed-in type|
+//! |`vis`|`syn::Visibility` or `darling::util::Visibility`|The visibility of the passed-in type|
 //! |`generics`|`T: darling::FromGenerics`|The generics of the passed-in type. This can be `syn::Generics`, `darling::ast::Generics`, or any compatible type.|
 //! |`data`|`darling::ast::Data` (or anything that implements `TryFrom<&syn::Data, Error=darling::Error>`, or anything using `#[darling(with = ...)]`)|The body of the passed-in type|
 //! |`attrs`|`Vec<syn::Attribute>` (or anything, using `#[darling(with = ...)]`)|The forwarded attributes from the passed in type. These are controlled using the `forward_attrs` attribute.|
@@ -60,8 +118,9 @@
 //! |Field name|Type|Meaning|
 //! |---|---|---|
 //! |`ident`|`Option<syn::Ident>` (or anything, using `#[darling(with = ...)]`)|The identifier of the passed-in field, or `None` for tuple fields|
-//! |`vis`|`syn::Visibility`|The visibility of the passed-in field|
+//! |`vis`|`syn::Visibility` or `darling::util::Visibility`|The visibility of the passed-in field|
 //! |`ty`|`syn::Type`|The type of the passed-in field|
+//! |`index`|`Option<usize>`|The position of the passed-in field within its parent, counting from zero. This is set when parsing through `darling::ast::Fields` or `from_field_at`; calling `from_field` directly gives `None`.|
 //! |`attrs`|`Vec<syn::Attribute>` (or anything, using `#[darling(with = ...)]`)|The forwarded attributes from the passed in field. These are controlled using the `forward_attrs` attribute.|
 //!
 //! ### [`FromTypeParam`]
@@ -112,13 +171,14 @@ pub use darling_core::ToTokens;
 pub mod export {
     pub use core::convert::{identity, From, Into, TryFrom};
     pub use core::default::Default;
-    pub use core::iter::IntoIterator;
+    pub use core::iter::{Extend, IntoIterator};
     pub use core::option::Option::{self, None, Some};
     pub use core::result::Result::{self, Err, Ok};
     pub use darling_core::syn;
     pub use std::clone::Clone;
+    pub use std::env;
     pub use std::marker::PhantomData;
-    pub use std::string::ToString;
+    pub use std::string::{String, ToString};
     pub use std::vec::Vec;
 
     pub use crate::ast::NestedMeta;
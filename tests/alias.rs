@@ -0,0 +1,121 @@
+//! Test that `alias` and `deprecated_alias` accept additional names for a field, that only
+//! deprecated aliases record a warning, and that the canonical field is still populated.
+
+use darling::FromDeriveInput;
+use syn::parse_quote;
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(widget))]
+struct Widget {
+    #[darling(
+        rename = "label",
+        deprecated_alias = "title",
+        deprecated_alias = "caption"
+    )]
+    text: String,
+    #[darling(default)]
+    width: u8,
+    #[darling(warnings)]
+    warnings: Vec<darling::Error>,
+}
+
+#[test]
+fn canonical_name() {
+    let widget = Widget::from_derive_input(&parse_quote! {
+        #[widget(label = "Hello")]
+        struct Foo;
+    })
+    .unwrap();
+
+    assert_eq!(widget.text, "Hello");
+    assert!(widget.warnings.is_empty());
+}
+
+#[test]
+fn alias_parses_with_warning() {
+    let widget = Widget::from_derive_input(&parse_quote! {
+        #[widget(title = "Hello", width = 3)]
+        struct Foo;
+    })
+    .unwrap();
+
+    assert_eq!(widget.text, "Hello");
+    assert_eq!(widget.width, 3);
+
+    let warnings = widget.warnings;
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].to_string(),
+        "`title` is deprecated; use `label` instead"
+    );
+    assert!(warnings[0].has_span());
+}
+
+#[test]
+fn second_alias() {
+    let widget = Widget::from_derive_input(&parse_quote! {
+        #[widget(caption = "Hello")]
+        struct Foo;
+    })
+    .unwrap();
+
+    assert_eq!(widget.text, "Hello");
+    assert_eq!(widget.warnings.len(), 1);
+}
+
+#[test]
+fn alias_and_canonical_conflict() {
+    let err = Widget::from_derive_input(&parse_quote! {
+        #[widget(label = "Hello", title = "World")]
+        struct Foo;
+    })
+    .unwrap_err();
+
+    // Both the canonical name and the alias are reported.
+    assert_eq!(err.len(), 2);
+    for error in err {
+        assert_eq!(error.to_string(), "Duplicate field `label`");
+    }
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(paint))]
+struct Paint {
+    #[darling(alias = "colour", alias = "color")]
+    hue: String,
+}
+
+#[test]
+fn each_alias_parses_without_warning() {
+    for input in [
+        parse_quote!(
+            #[paint(hue = "red")]
+            struct Foo;
+        ),
+        parse_quote!(
+            #[paint(colour = "red")]
+            struct Foo;
+        ),
+        parse_quote!(
+            #[paint(color = "red")]
+            struct Foo;
+        ),
+    ] {
+        assert_eq!(Paint::from_derive_input(&input).unwrap().hue, "red");
+    }
+}
+
+#[test]
+fn two_aliases_in_one_invocation() {
+    let err = Paint::from_derive_input(&parse_quote! {
+        #[paint(colour = "red", color = "blue")]
+        struct Foo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.len(), 2);
+    for error in err {
+        assert_eq!(error.to_string(), "Duplicate field `hue`");
+        assert!(error.has_span());
+    }
+}
@@ -78,3 +78,24 @@ fn errors_combined_with_others() {
     // Look for the invalid path from attrs conversion
     assert!(error.contains("`path::to::attr`"));
 }
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(a), forward_attrs(doc))]
+struct Documented {
+    #[darling(with = util::parse_docs)]
+    attrs: String,
+}
+
+#[test]
+fn collects_docs() {
+    let di = Documented::from_derive_input(&parse_quote! {
+        /// First line.
+        ///
+        /// Second paragraph.
+        #[a]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(di.attrs, "First line.\n\nSecond paragraph.");
+}
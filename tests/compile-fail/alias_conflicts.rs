@@ -0,0 +1,28 @@
+//! Test that an alias can't repeat its own field's name or a name accepted by another field.
+
+use darling::FromMeta;
+
+#[derive(FromMeta)]
+pub struct OwnName {
+    #[darling(alias = "label")]
+    label: String,
+}
+
+#[derive(FromMeta)]
+pub struct OtherName {
+    #[darling(alias = "width")]
+    height: u32,
+    width: u32,
+}
+
+#[derive(FromMeta)]
+pub struct OtherAlias {
+    #[darling(alias = "size")]
+    height: u32,
+    #[darling(deprecated_alias = "size")]
+    width: u32,
+    #[darling(warnings)]
+    warnings: Vec<darling::Error>,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use darling::FromMeta;
+
+#[derive(FromMeta)]
+struct Receiver {
+    #[darling(default_env = "TAGS", multiple)]
+    tags: Vec<String>,
+    #[darling(default_env = "NAME", skip)]
+    name: String,
+}
+
+#[derive(FromMeta)]
+struct Positional {
+    #[darling(positional, default_env = "PATH_ARG")]
+    path: String,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use darling::FromMeta;
+
+#[derive(FromMeta)]
+struct Inner {
+    left: String,
+}
+
+#[derive(FromMeta)]
+struct Receiver {
+    #[darling(deny_empty, flatten)]
+    inner: Inner,
+    #[darling(deny_empty, skip)]
+    names: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+use darling::FromMeta;
+
+fn parse_mode(_value: &str) -> darling::Result<Mode> {
+    Ok(Mode::Fast)
+}
+
+// This usage of `from_string` is invalid because enums already generate a `from_string`
+// method for their unit variants.
+#[derive(FromMeta)]
+#[darling(from_string = parse_mode)]
+enum Mode {
+    Fast,
+    Slow,
+}
+
+fn newtype_from_string(_value: &str) -> darling::Result<Newtype> {
+    Ok(Newtype(true))
+}
+
+// This usage of `from_string` is invalid because newtype structs call the inner type's `from_meta`
+// directly from their `from_meta`, so the custom `from_string` will never be called in normal usage.
+#[derive(FromMeta)]
+#[darling(from_string = newtype_from_string)]
+struct Newtype(bool);
+
+fn main() {}
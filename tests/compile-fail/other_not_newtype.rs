@@ -0,0 +1,27 @@
+//! Test that `other` can only be applied to a single newtype variant.
+
+use darling::FromMeta;
+
+#[derive(FromMeta)]
+enum Unit {
+    Known,
+    #[darling(other)]
+    Other,
+}
+
+#[derive(FromMeta)]
+enum Struct {
+    Known,
+    #[darling(other)]
+    Other { value: String },
+}
+
+#[derive(FromMeta)]
+enum Twice {
+    #[darling(other)]
+    First(String),
+    #[darling(other)]
+    Second(String),
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+//! Test that `positional` fields must come before named fields, and can't be combined
+//! with options that read the field by name.
+
+use darling::FromMeta;
+
+#[derive(FromMeta)]
+pub struct Example {
+    method: String,
+    #[darling(positional)]
+    path: String,
+}
+
+#[derive(FromMeta)]
+pub struct WithMultiple {
+    #[darling(multiple, positional)]
+    paths: Vec<String>,
+}
+
+#[derive(FromMeta)]
+pub struct WithBefore {
+    #[darling(with = darling::util::parse_expr::preserve_str_literal, positional)]
+    path: syn::Expr,
+}
+
+#[derive(FromMeta)]
+pub struct WithAfter {
+    #[darling(positional, with = darling::util::parse_expr::preserve_str_literal)]
+    path: syn::Expr,
+}
+
+fn main() {}
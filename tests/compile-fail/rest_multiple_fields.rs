@@ -0,0 +1,41 @@
+//! Test that only one field can be marked `rest`, and that it can't be combined with `flatten`
+//! or `with` in either order.
+
+use std::collections::HashMap;
+
+use darling::FromMeta;
+
+#[derive(FromMeta)]
+struct Inner {
+    left: String,
+}
+
+#[derive(FromMeta)]
+pub struct Example {
+    #[darling(rest)]
+    first: HashMap<String, syn::Meta>,
+    #[darling(rest)]
+    last: HashMap<String, syn::Meta>,
+}
+
+#[derive(FromMeta)]
+pub struct WithFlatten {
+    #[darling(flatten)]
+    inner: Inner,
+    #[darling(rest)]
+    rest: HashMap<String, syn::Meta>,
+}
+
+#[derive(FromMeta)]
+pub struct WithBefore {
+    #[darling(with = darling::util::parse_expr::preserve_str_literal, rest)]
+    rest: HashMap<String, syn::Meta>,
+}
+
+#[derive(FromMeta)]
+pub struct WithAfter {
+    #[darling(rest, with = darling::util::parse_expr::preserve_str_literal)]
+    rest: HashMap<String, syn::Meta>,
+}
+
+fn main() {}
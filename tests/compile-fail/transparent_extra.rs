@@ -8,4 +8,9 @@ struct Lorem3 {
     foo: bool,
 }
 
+// Tuple structs are no different
+#[derive(Debug, FromMeta, PartialEq, Eq)]
+#[darling(transparent)]
+struct Pair(u16, u16);
+
 fn main() {}
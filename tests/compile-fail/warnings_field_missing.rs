@@ -0,0 +1,25 @@
+//! Test that warnings need exactly one `#[darling(warnings)]` field to be stored in.
+
+use darling::FromMeta;
+
+#[derive(FromMeta)]
+#[darling(unknown_fields = "warn")]
+struct Lenient {
+    known: bool,
+}
+
+#[derive(FromMeta)]
+struct Renamed {
+    #[darling(deprecated_alias = "old")]
+    new: bool,
+}
+
+#[derive(FromMeta)]
+struct Twice {
+    #[darling(warnings)]
+    first: Vec<darling::Error>,
+    #[darling(warnings)]
+    second: Vec<darling::Error>,
+}
+
+fn main() {}
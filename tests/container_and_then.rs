@@ -0,0 +1,75 @@
+//! Test that container-level `and_then` can validate fields against each other, and that
+//! its errors are combined with other errors from the same parse.
+
+use darling::{Error, FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+#[darling(and_then = Self::validate)]
+struct Range {
+    min: u32,
+    max: u32,
+    #[darling(default)]
+    step: Option<u32>,
+}
+
+impl Range {
+    fn validate(self) -> darling::Result<Self> {
+        let mut errors = Error::accumulator();
+
+        if self.min > self.max {
+            errors.push(Error::custom("`min` must not exceed `max`").at("min"));
+        }
+
+        if let Some(step) = self.step {
+            if step > self.max.saturating_sub(self.min) {
+                errors.push(Error::custom("`step` is larger than the range").at("step"));
+            }
+        }
+
+        errors.finish_with(self)
+    }
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(sample))]
+#[allow(dead_code)]
+struct Receiver {
+    range: Range,
+    label: String,
+}
+
+#[test]
+fn valid() {
+    let range = Range::from_list(&[parse_quote!(min = 1), parse_quote!(max = 5)]).unwrap();
+    assert_eq!((range.min, range.max, range.step), (1, 5, None));
+}
+
+#[test]
+fn sibling_dependent_error() {
+    let err = Range::from_list(&[parse_quote!(min = 5), parse_quote!(max = 1)]).unwrap_err();
+    assert_eq!(err.to_string(), "`min` must not exceed `max` at min");
+}
+
+#[test]
+fn several_errors() {
+    let err = Range::from_list(&[
+        parse_quote!(min = 5),
+        parse_quote!(max = 1),
+        parse_quote!(step = 10),
+    ])
+    .unwrap_err();
+    assert_eq!(err.len(), 2);
+}
+
+#[test]
+fn merged_with_parent_errors() {
+    let err = Receiver::from_derive_input(&parse_quote! {
+        #[sample(range(min = 5, max = 1))]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    // One error from `Range::validate` and one for the missing `label`.
+    assert_eq!(err.len(), 2);
+}
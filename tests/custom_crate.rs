@@ -43,3 +43,18 @@ fn renamed_darling_syn() {
     let input = syn::parse2::<InputAttr>(quote! { foo }).unwrap();
     assert!(input.foo.is_present());
 }
+
+// Renamed using a string literal, as with serde's `#[serde(crate = "...")]`
+#[derive(::darling::FromMeta)]
+#[darling(crate = "inner::renamed_darling")]
+struct StringPath {
+    level: u8,
+}
+
+#[test]
+fn renamed_darling_string_path() {
+    use ::darling::FromMeta;
+
+    let parsed = StringPath::from_list(&[syn::parse_quote!(level = 3)]).unwrap();
+    assert_eq!(parsed.level, 3);
+}
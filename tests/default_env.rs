@@ -0,0 +1,82 @@
+//! Fields can fall back to an environment variable read while the macro is expanding.
+//!
+//! These tests never modify the environment, since tests run in parallel. They read
+//! variables that Cargo sets for every test binary, or names that are never set.
+
+use darling::FromMeta;
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+struct Release {
+    #[darling(default_env = "CARGO_PKG_VERSION")]
+    version: String,
+}
+
+#[derive(Debug, FromMeta)]
+struct Build {
+    #[darling(default_env = "CARGO_PKG_VERSION_MAJOR")]
+    major: u8,
+}
+
+#[derive(Debug, FromMeta)]
+#[allow(dead_code)]
+struct BadBuild {
+    #[darling(default_env = "CARGO_PKG_NAME")]
+    jobs: u8,
+}
+
+#[derive(Debug, FromMeta)]
+struct Channel {
+    #[darling(default_env = "DARLING_TEST_CHANNEL_NAME", default = || "stable".to_string())]
+    name: String,
+}
+
+#[test]
+fn explicit_value_wins() {
+    let release = Release::from_list(&[parse_quote!(version = "0.1.0")]).unwrap();
+    assert_eq!(release.version, "0.1.0");
+}
+
+#[test]
+fn var_set() {
+    let release = Release::from_list(&[]).unwrap();
+    assert_eq!(release.version, env!("CARGO_PKG_VERSION"));
+}
+
+#[test]
+fn var_parsed_as_field_type() {
+    assert_eq!(
+        Build::from_list(&[]).unwrap().major.to_string(),
+        env!("CARGO_PKG_VERSION_MAJOR")
+    );
+
+    let err = BadBuild::from_list(&[]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!("Unknown value: `{}` at jobs", env!("CARGO_PKG_NAME"))
+    );
+}
+
+#[test]
+fn var_unset() {
+    #[derive(Debug, FromMeta)]
+    struct Unset {
+        #[darling(default_env = "DARLING_TEST_NEVER_SET")]
+        version: String,
+    }
+
+    let err = Unset::from_list(&[]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Missing field `version` and environment variable `DARLING_TEST_NEVER_SET` is not set"
+    );
+    assert_eq!(err.code(), Some("darling::missing_field"));
+
+    let explicit = Unset::from_list(&[parse_quote!(version = "2.0.0")]).unwrap();
+    assert_eq!(explicit.version, "2.0.0");
+}
+
+#[test]
+fn var_unset_with_default() {
+    assert_eq!(Channel::from_list(&[]).unwrap().name, "stable");
+}
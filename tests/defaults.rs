@@ -197,3 +197,169 @@ mod overridden_implicit_default {
         assert!(!person.lefty.is_present());
     }
 }
+
+/// Test that `default_with` calls a fallible function, and that an error returned
+/// from that function is reported alongside other parsing errors.
+mod fallible_default {
+    use darling::{Error, FromDeriveInput, Result};
+    use syn::parse_quote;
+
+    fn default_port() -> Result<u16> {
+        Ok(8080)
+    }
+
+    fn default_tags() -> Result<Vec<String>> {
+        Ok(vec!["default".into()])
+    }
+
+    fn unavailable() -> Result<String> {
+        Err(Error::custom("HOST_NAME is not set"))
+    }
+
+    #[derive(FromDeriveInput)]
+    #[darling(attributes(server))]
+    struct Server {
+        #[darling(default_with = default_port)]
+        port: u16,
+        #[darling(multiple, rename = "tag", default_with = "default_tags")]
+        tags: Vec<String>,
+    }
+
+    #[derive(Debug, FromDeriveInput)]
+    #[darling(attributes(server))]
+    #[allow(dead_code)]
+    struct Host {
+        #[darling(default_with = unavailable)]
+        name: String,
+        port: u16,
+    }
+
+    #[test]
+    fn uses_fallible_default() {
+        let server = Server::from_derive_input(&parse_quote! {
+            struct Foo;
+        })
+        .unwrap();
+
+        assert_eq!(server.port, 8080);
+        assert_eq!(server.tags, vec!["default".to_string()]);
+    }
+
+    #[test]
+    fn explicit_value_skips_default() {
+        let server = Server::from_derive_input(&parse_quote! {
+            #[server(port = 80, tag = "web")]
+            struct Foo;
+        })
+        .unwrap();
+
+        assert_eq!(server.port, 80);
+        assert_eq!(server.tags, vec!["web".to_string()]);
+    }
+
+    #[test]
+    fn explicit_value_skips_failing_default() {
+        let host = Host::from_derive_input(&parse_quote! {
+            #[server(name = "localhost", port = 80)]
+            struct Foo;
+        })
+        .unwrap();
+
+        assert_eq!(host.name, "localhost");
+    }
+
+    #[test]
+    fn default_error_is_accumulated() {
+        let err = Host::from_derive_input(&parse_quote! {
+            #[server(port = "http")]
+            struct Foo;
+        })
+        .unwrap_err();
+
+        assert_eq!(err.len(), 2);
+        let messages = err
+            .flatten()
+            .into_iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+        assert!(messages.contains(&"HOST_NAME is not set at name".to_string()));
+    }
+}
+
+mod cow_default {
+    use std::borrow::Cow;
+
+    use darling::FromDeriveInput;
+    use syn::parse_quote;
+
+    #[derive(FromDeriveInput)]
+    #[darling(attributes(speak))]
+    struct Receiver {
+        #[darling(default = || Cow::Borrowed("hello"))]
+        word: Cow<'static, str>,
+    }
+
+    #[test]
+    fn borrowed_default() {
+        let receiver = Receiver::from_derive_input(&parse_quote! {
+            struct Foo;
+        })
+        .unwrap();
+
+        assert!(matches!(receiver.word, Cow::Borrowed("hello")));
+    }
+
+    #[test]
+    fn owned_from_literal() {
+        let receiver = Receiver::from_derive_input(&parse_quote! {
+            #[speak(word = "world")]
+            struct Foo;
+        })
+        .unwrap();
+
+        assert!(matches!(receiver.word, Cow::Owned(_)));
+        assert_eq!(receiver.word, "world");
+    }
+}
+
+/// A container-level `default` fills every absent field from `Self::default()`, without
+/// annotating each field.
+mod container_default_from_meta {
+    use darling::FromMeta;
+    use syn::parse_quote;
+
+    #[derive(Debug, PartialEq, Eq, FromMeta)]
+    #[darling(default)]
+    struct Limits {
+        min: u8,
+        max: u8,
+        step: u8,
+    }
+
+    impl Default for Limits {
+        fn default() -> Self {
+            Self {
+                min: 1,
+                max: 10,
+                step: 2,
+            }
+        }
+    }
+
+    #[test]
+    fn one_field_specified() {
+        assert_eq!(
+            Limits::from_list(&[parse_quote!(max = 99)]).unwrap(),
+            Limits {
+                min: 1,
+                max: 99,
+                step: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn no_fields_specified() {
+        assert_eq!(Limits::from_list(&[]).unwrap(), Limits::default());
+    }
+}
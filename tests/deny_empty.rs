@@ -0,0 +1,67 @@
+//! Fields marked `deny_empty` reject an explicitly empty list or array.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+struct Pipeline {
+    #[darling(deny_empty)]
+    stages: Vec<String>,
+    #[darling(deny_empty, default)]
+    tags: Vec<String>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(sample))]
+struct Receiver {
+    #[darling(deny_empty, multiple)]
+    group: Vec<Vec<String>>,
+}
+
+#[test]
+fn empty_list_errors() {
+    let err = Pipeline::from_list(&[parse_quote!(stages())]).unwrap_err();
+    assert!(err.has_span());
+    assert_eq!(err.to_string(), "expected at least one value at stages");
+}
+
+#[test]
+fn empty_array_errors() {
+    let err =
+        Pipeline::from_list(&[parse_quote!(stages = ["a"]), parse_quote!(tags = [])]).unwrap_err();
+    assert_eq!(err.to_string(), "expected at least one value at tags");
+}
+
+#[test]
+fn populated_succeeds() {
+    let pipeline =
+        Pipeline::from_list(&[parse_quote!(stages("a", "b")), parse_quote!(tags = ["x"])]).unwrap();
+    assert_eq!(pipeline.stages, vec!["a", "b"]);
+    assert_eq!(pipeline.tags, vec!["x"]);
+}
+
+#[test]
+fn absent_unchanged() {
+    let pipeline = Pipeline::from_list(&[parse_quote!(stages("a"))]).unwrap();
+    assert!(pipeline.tags.is_empty());
+
+    let err = Pipeline::from_list(&[]).unwrap_err();
+    assert_eq!(err.to_string(), "Missing field `stages`");
+}
+
+#[test]
+fn multiple_checks_each_occurrence() {
+    let receiver = Receiver::from_derive_input(&parse_quote! {
+        #[sample(group("a"), group("b", "c"))]
+        struct Demo;
+    })
+    .unwrap();
+    assert_eq!(receiver.group.len(), 2);
+
+    let err = Receiver::from_derive_input(&parse_quote! {
+        #[sample(group("a"), group())]
+        struct Demo;
+    })
+    .unwrap_err();
+    assert_eq!(err.to_string(), "expected at least one value at group[1]");
+}
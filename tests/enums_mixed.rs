@@ -0,0 +1,111 @@
+//! Tests for `FromMeta` enums which mix unit variants with struct or newtype variants.
+//! The shape of each item decides which kind of variant it can match.
+
+use darling::{Error, FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+enum Mode {
+    Auto,
+    Manual { value: u8 },
+    Fixed(u8),
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(tune))]
+struct Receiver {
+    mode: Mode,
+}
+
+fn mode_of(input: syn::DeriveInput) -> darling::Result<Mode> {
+    Receiver::from_derive_input(&input).map(|receiver| receiver.mode)
+}
+
+#[test]
+fn unit_variant_as_word() {
+    let mode = mode_of(parse_quote! {
+        #[tune(mode(auto))]
+        struct Foo;
+    })
+    .unwrap();
+    assert_eq!(mode, Mode::Auto);
+}
+
+#[test]
+fn unit_variant_as_string() {
+    let mode = mode_of(parse_quote! {
+        #[tune(mode = "auto")]
+        struct Foo;
+    })
+    .unwrap();
+    assert_eq!(mode, Mode::Auto);
+}
+
+#[test]
+fn unit_variant_as_empty_list() {
+    let mode = mode_of(parse_quote! {
+        #[tune(mode(auto()))]
+        struct Foo;
+    })
+    .unwrap();
+    assert_eq!(mode, Mode::Auto);
+}
+
+#[test]
+fn struct_variant_as_list() {
+    let mode = mode_of(parse_quote! {
+        #[tune(mode(manual(value = 3)))]
+        struct Foo;
+    })
+    .unwrap();
+    assert_eq!(mode, Mode::Manual { value: 3 });
+}
+
+#[test]
+fn newtype_variant_as_name_value() {
+    let mode = mode_of(parse_quote! {
+        #[tune(mode(fixed = 7))]
+        struct Foo;
+    })
+    .unwrap();
+    assert_eq!(mode, Mode::Fixed(7));
+}
+
+#[test]
+fn unit_variant_rejects_list() {
+    let err = mode_of(parse_quote! {
+        #[tune(mode(auto(value = 3)))]
+        struct Foo;
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        Error::unsupported_format("non-path").at("mode").to_string()
+    );
+}
+
+#[test]
+fn struct_variant_rejects_word() {
+    let err = mode_of(parse_quote! {
+        #[tune(mode(manual))]
+        struct Foo;
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        Error::unsupported_format("non-list").at("mode").to_string()
+    );
+}
+
+#[test]
+fn struct_variant_rejects_string() {
+    let err = mode_of(parse_quote! {
+        #[tune(mode = "manual")]
+        struct Foo;
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        Error::unsupported_format("literal").at("mode").to_string()
+    );
+}
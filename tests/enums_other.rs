@@ -0,0 +1,69 @@
+//! Test that a `#[darling(other)]` newtype variant receives values which don't
+//! match any known variant.
+
+use darling::FromMeta;
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+enum Level {
+    Debug,
+    Info,
+    #[darling(rename = "warn")]
+    Warning,
+    #[darling(other)]
+    Other(String),
+}
+
+#[derive(Debug, FromMeta)]
+struct Logging {
+    level: Level,
+}
+
+#[test]
+fn known_variant() {
+    assert_eq!(Level::from_string("info").unwrap(), Level::Info);
+    assert_eq!(Level::from_string("warn").unwrap(), Level::Warning);
+    assert_eq!(
+        Logging::from_meta(&parse_quote!(logging(level = "debug")))
+            .unwrap()
+            .level,
+        Level::Debug
+    );
+}
+
+#[test]
+fn fallback_captures_raw_string() {
+    assert_eq!(
+        Level::from_string("verbose").unwrap(),
+        Level::Other("verbose".into())
+    );
+    assert_eq!(
+        Logging::from_meta(&parse_quote!(logging(level = "TRACE")))
+            .unwrap()
+            .level,
+        Level::Other("TRACE".into())
+    );
+}
+
+#[test]
+fn other_is_not_matched_by_name() {
+    assert_eq!(
+        Level::from_string("other").unwrap(),
+        Level::Other("other".into())
+    );
+    Level::from_meta(&parse_quote!(level(other = "x"))).unwrap_err();
+}
+
+#[test]
+fn only_other_variant() {
+    #[derive(Debug, PartialEq, Eq, FromMeta)]
+    enum Anything {
+        #[darling(other)]
+        Value(String),
+    }
+
+    assert_eq!(
+        Anything::from_string("x").unwrap(),
+        Anything::Value("x".into())
+    );
+}
@@ -13,3 +13,76 @@ enum Message {
 
 #[test]
 fn expansion() {}
+
+mod output {
+    use darling::{FromDeriveInput, FromMeta};
+    use syn::parse_quote;
+
+    #[derive(Debug, PartialEq, Eq, FromMeta)]
+    #[darling(rename_all = "snake_case")]
+    enum Output {
+        File { path: String, append: bool },
+        Stdout,
+    }
+
+    #[derive(Debug, FromDeriveInput)]
+    #[darling(attributes(logger))]
+    struct Receiver {
+        output: Output,
+    }
+
+    fn parse(input: syn::DeriveInput) -> darling::Result<Output> {
+        Receiver::from_derive_input(&input).map(|receiver| receiver.output)
+    }
+
+    #[test]
+    fn struct_variant() {
+        assert_eq!(
+            parse(parse_quote! {
+                #[logger(output(file(path = "out.log", append = true)))]
+                struct Demo;
+            })
+            .unwrap(),
+            Output::File {
+                path: "out.log".to_string(),
+                append: true,
+            }
+        );
+    }
+
+    #[test]
+    fn unit_variant() {
+        assert_eq!(
+            parse(parse_quote! {
+                #[logger(output(stdout))]
+                struct Demo;
+            })
+            .unwrap(),
+            Output::Stdout
+        );
+    }
+
+    #[test]
+    fn struct_variant_field_errors() {
+        let err = parse(parse_quote! {
+            #[logger(output(file(append = true)))]
+            struct Demo;
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "Missing field `path` at output/file");
+    }
+
+    #[test]
+    fn two_variants_at_once() {
+        let err = parse(parse_quote! {
+            #[logger(output(stdout, file(path = "out.log", append = false)))]
+            struct Demo;
+        })
+        .unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(
+            err.to_string(),
+            "Too many items: Expected no more than 1 at output"
+        );
+    }
+}
@@ -0,0 +1,116 @@
+//! Tests for internally-tagged `FromMeta` enums, where `#[darling(tag = "...")]` names an
+//! item whose value selects the variant and the remaining items populate it.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(tag = "kind")]
+enum Shape {
+    Circle {
+        radius: u32,
+    },
+    Rect {
+        width: u32,
+        height: u32,
+    },
+    Square(Side),
+    #[darling(rename = "dot")]
+    Point,
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+struct Side {
+    side: u32,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(draw))]
+struct Receiver {
+    shape: Shape,
+}
+
+fn shape_of(input: syn::DeriveInput) -> darling::Result<Shape> {
+    Receiver::from_derive_input(&input).map(|receiver| receiver.shape)
+}
+
+#[test]
+fn struct_variants() {
+    let circle = shape_of(parse_quote! {
+        #[draw(shape(kind = "circle", radius = 2))]
+        struct Foo;
+    })
+    .unwrap();
+    assert_eq!(circle, Shape::Circle { radius: 2 });
+
+    // The tag doesn't need to come first.
+    let rect = shape_of(parse_quote! {
+        #[draw(shape(width = 3, kind = "rect", height = 4))]
+        struct Foo;
+    })
+    .unwrap();
+    assert_eq!(
+        rect,
+        Shape::Rect {
+            width: 3,
+            height: 4
+        }
+    );
+}
+
+#[test]
+fn newtype_variant() {
+    let square =
+        Shape::from_list(&[parse_quote!(kind = "square"), parse_quote!(side = 5)]).unwrap();
+    assert_eq!(square, Shape::Square(Side { side: 5 }));
+}
+
+#[test]
+fn unit_variant() {
+    assert_eq!(
+        Shape::from_list(&[parse_quote!(kind = "dot")]).unwrap(),
+        Shape::Point
+    );
+
+    let err =
+        Shape::from_list(&[parse_quote!(kind = "dot"), parse_quote!(radius = 1)]).unwrap_err();
+    assert_eq!(err.to_string(), "Unknown field: `radius`");
+}
+
+#[test]
+fn unknown_tag() {
+    let err = shape_of(parse_quote! {
+        #[draw(shape(kind = "hexagon", side = 1))]
+        struct Foo;
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unknown value: `hexagon`. Available values: `circle`, `dot`, `rect`, `square` at shape/kind"
+    );
+    assert!(err.has_span());
+}
+
+#[test]
+fn missing_tag() {
+    let err = Shape::from_list(&[parse_quote!(radius = 2)]).unwrap_err();
+    assert_eq!(err.to_string(), "Missing field `kind`");
+}
+
+#[test]
+fn duplicate_tag() {
+    let err = Shape::from_list(&[
+        parse_quote!(kind = "circle"),
+        parse_quote!(kind = "rect"),
+        parse_quote!(radius = 2),
+    ])
+    .unwrap_err();
+    assert_eq!(err.to_string(), "Duplicate field `kind`");
+}
+
+#[test]
+fn variant_field_errors() {
+    let err =
+        Shape::from_list(&[parse_quote!(kind = "rect"), parse_quote!(width = 1)]).unwrap_err();
+    assert_eq!(err.to_string(), "Missing field `height`");
+}
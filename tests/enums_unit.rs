@@ -150,3 +150,57 @@ fn rejected_in_unit_enum_variants() {
         )
     }
 }
+
+#[derive(Debug, FromMeta, PartialEq, Eq)]
+#[darling(rename_all = "kebab-case")]
+enum KebabMode {
+    FastPath,
+    SlowLane,
+    #[darling(rename = "legacy")]
+    LegacyMode,
+}
+
+#[derive(Debug, FromMeta, PartialEq, Eq)]
+#[darling(rename_all = "camelCase")]
+enum CamelMode {
+    FastPath,
+    SlowLane,
+    #[darling(rename = "legacy")]
+    LegacyMode,
+}
+
+#[test]
+fn rename_all_kebab_case() {
+    assert_eq!(
+        KebabMode::from_string("fast-path").unwrap(),
+        KebabMode::FastPath
+    );
+    assert_eq!(
+        KebabMode::from_meta(&parse_quote!(mode = "slow-lane")).unwrap(),
+        KebabMode::SlowLane
+    );
+    assert_eq!(
+        KebabMode::from_string("legacy").unwrap(),
+        KebabMode::LegacyMode
+    );
+    KebabMode::from_string("legacy-mode").unwrap_err();
+    KebabMode::from_string("fast_path").unwrap_err();
+}
+
+#[test]
+fn rename_all_camel_case() {
+    assert_eq!(
+        CamelMode::from_string("fastPath").unwrap(),
+        CamelMode::FastPath
+    );
+    assert_eq!(
+        CamelMode::from_meta(&parse_quote!(mode(slowLane))).unwrap(),
+        CamelMode::SlowLane
+    );
+    assert_eq!(
+        CamelMode::from_string("legacy").unwrap(),
+        CamelMode::LegacyMode
+    );
+    CamelMode::from_string("legacyMode").unwrap_err();
+    CamelMode::from_string("fast_path").unwrap_err();
+}
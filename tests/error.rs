@@ -52,3 +52,89 @@ fn missing_field() {
 
     println!("{}", Lorem::from_derive_input(&di).unwrap_err());
 }
+
+#[derive(Debug, FromMeta)]
+struct Package {
+    #[darling(missing_message = "you must specify `name` (see the docs)")]
+    name: String,
+    version: String,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(hello))]
+struct Manifest {
+    package: Package,
+}
+
+#[test]
+fn custom_missing_message() {
+    let di = parse_quote! {
+        #[hello(package(version = "1.0"))]
+        pub struct Foo;
+    };
+
+    let err = Manifest::from_derive_input(&di).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "you must specify `name` (see the docs) at package"
+    );
+    assert_eq!(err.code(), Some("darling::missing_field"));
+    assert!(err.has_span());
+}
+
+#[test]
+fn default_missing_message() {
+    let di = parse_quote! {
+        #[hello(package(name = "demo"))]
+        pub struct Foo;
+    };
+
+    let err = Manifest::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.to_string(), "Missing field `version` at package");
+    assert!(err.has_span());
+}
+
+#[derive(Debug, FromMeta)]
+struct Retry {
+    attempts: u8,
+}
+
+#[derive(Debug, FromMeta)]
+struct Client {
+    retry: Retry,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(hello))]
+struct Service {
+    client: Client,
+}
+
+#[test]
+fn nested_unknown_field_location() {
+    let di = parse_quote! {
+        #[hello(client(retry(attempts = 3, backoff = 10)))]
+        pub struct Foo;
+    };
+
+    let err = Service::from_derive_input(&di).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unknown field: `backoff`. Available values: `attempts` at client/retry"
+    );
+    assert!(err.has_span());
+}
+
+#[test]
+fn nested_value_error_location() {
+    let di = parse_quote! {
+        #[hello(client(retry(attempts = "many")))]
+        pub struct Foo;
+    };
+
+    let err = Service::from_derive_input(&di).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unknown value: `many` at client/retry/attempts"
+    );
+}
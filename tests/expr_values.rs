@@ -0,0 +1,64 @@
+//! Fields typed as `syn::Expr` capture the full right-hand side of `name = value`,
+//! so computed values can be forwarded into generated `const` contexts.
+
+use darling::FromDeriveInput;
+use quote::quote;
+use syn::{parse_quote, Expr, ItemConst};
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(buffer))]
+struct Buffer {
+    size: Expr,
+}
+
+fn size_of(di: syn::DeriveInput) -> Expr {
+    Buffer::from_derive_input(&di).unwrap().size
+}
+
+fn as_const(size: &Expr) -> ItemConst {
+    syn::parse2(quote!(const SIZE: usize = #size;)).unwrap()
+}
+
+#[test]
+fn literal_expr() {
+    let size = size_of(parse_quote! {
+        #[buffer(size = 8)]
+        struct Foo;
+    });
+
+    assert_eq!(size, parse_quote!(8));
+    assert_eq!(*as_const(&size).expr, parse_quote!(8));
+}
+
+#[test]
+fn binary_expr() {
+    let size = size_of(parse_quote! {
+        #[buffer(size = 2 * 4)]
+        struct Foo;
+    });
+
+    assert!(matches!(size, Expr::Binary(_)));
+    assert_eq!(size, parse_quote!(2 * 4));
+    assert_eq!(*as_const(&size).expr, parse_quote!(2 * 4));
+}
+
+#[test]
+fn path_expr() {
+    let size = size_of(parse_quote! {
+        #[buffer(size = consts::PAGE_SIZE)]
+        struct Foo;
+    });
+
+    assert!(matches!(size, Expr::Path(_)));
+    assert_eq!(size, parse_quote!(consts::PAGE_SIZE));
+}
+
+#[test]
+fn quoted_expr_is_unwrapped() {
+    let size = size_of(parse_quote! {
+        #[buffer(size = "2 * 4")]
+        struct Foo;
+    });
+
+    assert_eq!(size, parse_quote!(2 * 4));
+}
@@ -0,0 +1,89 @@
+//! Test that field-level `and_then` validates a parsed value, runs after `with` and `map`,
+//! and reports failures at the field's location.
+
+use darling::{Error, FromDeriveInput, FromMeta, Result};
+use syn::parse_quote;
+
+fn nonzero_port(port: u16) -> Result<u16> {
+    if port == 0 {
+        Err(Error::custom("port must not be zero"))
+    } else {
+        Ok(port)
+    }
+}
+
+fn double(value: u16) -> u16 {
+    value * 2
+}
+
+fn below_limit(value: u16) -> Result<u16> {
+    if value > 100 {
+        Err(Error::custom("value exceeds limit"))
+    } else {
+        Ok(value)
+    }
+}
+
+fn parse_hex(meta: &syn::Meta) -> Result<u16> {
+    let value = String::from_meta(meta)?;
+    u16::from_str_radix(&value, 16).map_err(|_| Error::unknown_value(&value))
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(server))]
+struct Server {
+    #[darling(and_then = nonzero_port)]
+    port: u16,
+    // `and_then` is declared first, but still runs after `map`.
+    #[darling(default, and_then = below_limit, map = double)]
+    scaled: u16,
+    #[darling(default, with = parse_hex, and_then = below_limit)]
+    hex: u16,
+}
+
+#[test]
+fn passing_validation() {
+    let server = Server::from_derive_input(&parse_quote! {
+        #[server(port = 8080, scaled = 50, hex = "1f")]
+        struct Foo;
+    })
+    .unwrap();
+
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.scaled, 100);
+    assert_eq!(server.hex, 31);
+}
+
+#[test]
+fn failing_validation_reports_field_span() {
+    let err = Server::from_derive_input(&parse_quote! {
+        #[server(port = 0)]
+        struct Foo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "port must not be zero at port");
+    assert!(err.has_span());
+}
+
+#[test]
+fn runs_after_map() {
+    let err = Server::from_derive_input(&parse_quote! {
+        #[server(port = 1, scaled = 51)]
+        struct Foo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "value exceeds limit at scaled");
+}
+
+#[test]
+fn runs_after_with() {
+    let err = Server::from_derive_input(&parse_quote! {
+        #[server(port = 1, hex = "ff")]
+        struct Foo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "value exceeds limit at hex");
+}
@@ -0,0 +1,82 @@
+//! `FromField` can forward the position of each field within its parent.
+
+use darling::{ast, FromDeriveInput, FromField};
+use syn::parse_quote;
+
+#[derive(Debug, FromField)]
+#[darling(attributes(column))]
+struct Column {
+    ident: Option<syn::Ident>,
+    index: Option<usize>,
+    #[darling(default)]
+    name: Option<String>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(column))]
+struct Table {
+    data: ast::Data<(), Column>,
+}
+
+fn indices(input: syn::DeriveInput) -> Vec<Option<usize>> {
+    Table::from_derive_input(&input)
+        .unwrap()
+        .data
+        .take_struct()
+        .unwrap()
+        .into_iter()
+        .map(|column| column.index)
+        .collect()
+}
+
+#[test]
+fn tuple_struct_indices() {
+    assert_eq!(
+        indices(parse_quote! {
+            struct Row(u8, #[column(name = "second")] String, bool);
+        }),
+        vec![Some(0), Some(1), Some(2)]
+    );
+}
+
+#[test]
+fn named_struct_source_order() {
+    assert_eq!(
+        indices(parse_quote! {
+            struct Row {
+                b: u8,
+                a: u8,
+            }
+        }),
+        vec![Some(0), Some(1)]
+    );
+}
+
+#[test]
+fn other_fields_still_parsed() {
+    let fields = Table::from_derive_input(&parse_quote! {
+        struct Row(u8, #[column(name = "second")] String);
+    })
+    .unwrap()
+    .data
+    .take_struct()
+    .unwrap();
+
+    assert_eq!(fields.fields[1].ident, None);
+    assert_eq!(fields.fields[1].name.as_deref(), Some("second"));
+}
+
+#[test]
+fn from_field_index_unknown() {
+    let field: syn::Field = syn::Field {
+        attrs: vec![],
+        vis: syn::Visibility::Inherited,
+        mutability: syn::FieldMutability::None,
+        ident: None,
+        colon_token: None,
+        ty: parse_quote!(u8),
+    };
+
+    assert_eq!(Column::from_field(&field).unwrap().index, None);
+    assert_eq!(Column::from_field_at(&field, 4).unwrap().index, Some(4));
+}
@@ -235,3 +235,32 @@ fn flattening_to_box() {
 
     assert!(!when_omitted.items.public.is_present());
 }
+
+/// A field declared by both the parent and the flattened struct is consumed by the parent.
+/// The child only sees the leftovers, so its copy of the field falls back to `from_none`.
+#[test]
+fn parent_field_shadows_flattened_field() {
+    #[derive(FromMeta)]
+    struct Common {
+        label: Option<String>,
+        verbose: Flag,
+    }
+
+    #[derive(FromDeriveInput)]
+    #[darling(attributes(sample))]
+    struct Shadowing {
+        label: String,
+        #[darling(flatten)]
+        common: Common,
+    }
+
+    let parsed = Shadowing::from_derive_input(&parse_quote! {
+        #[sample(label = "Parent", verbose)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(parsed.label, "Parent");
+    assert_eq!(parsed.common.label, None);
+    assert!(parsed.common.verbose.is_present());
+}
@@ -20,3 +20,26 @@ fn forward_attrs_with_field() {
     assert!(parsed.default.is_some());
     assert_eq!(parsed.attrs.len(), 1);
 }
+
+/// With no `attributes(...)` to parse, a bare `forward_attrs` captures every attribute.
+#[derive(darling::FromAttributes)]
+#[darling(forward_attrs)]
+struct AllAttrs {
+    attrs: Vec<syn::Attribute>,
+}
+
+#[test]
+fn forward_all_attrs() {
+    let input: syn::DeriveInput = parse_quote! {
+        /// Documented
+        #[doc = "Hello"]
+        #[builder(default = 15)]
+        #[cfg(test)]
+        #[serde(rename = "example")]
+        struct Example;
+    };
+
+    let parsed = AllAttrs::from_attributes(&input.attrs).unwrap();
+    assert_eq!(parsed.attrs, input.attrs);
+    assert!(parsed.attrs[0].path().is_ident("doc"));
+}
@@ -0,0 +1,60 @@
+//! `FromAttributes::from_attributes_with_rest` returns the attributes that weren't parsed.
+
+use darling::FromAttributes;
+use syn::{parse_quote, Attribute};
+
+#[derive(Debug, FromAttributes)]
+#[darling(attributes(builder, my_macro::setter))]
+struct Params {
+    #[darling(default)]
+    default: Option<syn::Expr>,
+    #[darling(default)]
+    skip: bool,
+}
+
+#[derive(Debug, FromAttributes)]
+#[darling(transparent)]
+struct Wrapper {
+    params: Params,
+}
+
+fn attrs() -> Vec<Attribute> {
+    let input: syn::DeriveInput = parse_quote! {
+        #[doc = "Hello"]
+        #[builder(default = 15)]
+        #[serde(rename = "other")]
+        #[my_macro::setter(skip)]
+        #[setter(ignored)]
+        struct Example;
+    };
+
+    input.attrs
+}
+
+#[test]
+fn leftovers_exclude_parsed() {
+    let attrs = attrs();
+    let (params, rest) = Params::from_attributes_with_rest(&attrs).unwrap();
+
+    assert!(params.default.is_some());
+    assert!(params.skip);
+    assert_eq!(
+        rest,
+        vec![attrs[0].clone(), attrs[2].clone(), attrs[4].clone()]
+    );
+}
+
+#[test]
+fn transparent_forwards_leftovers() {
+    let attrs = attrs();
+    let (wrapper, rest) = Wrapper::from_attributes_with_rest(&attrs).unwrap();
+
+    assert!(wrapper.params.skip);
+    assert_eq!(rest.len(), 3);
+}
+
+#[test]
+fn errors_are_returned() {
+    let attrs: Vec<Attribute> = vec![parse_quote!(#[builder(unknown)])];
+    assert!(Params::from_attributes_with_rest(&attrs).is_err());
+}
@@ -412,3 +412,87 @@ mod from_word_enum_closure {
         assert_eq!(thing.len(), 1);
     }
 }
+
+#[test]
+fn from_token_stream() {
+    let meta = Meta::from_token_stream(quote::quote!(meta1 = "thefeature", meta2)).unwrap();
+    assert_eq!(meta.meta1, Some("thefeature".to_string()));
+    assert!(meta.meta2);
+}
+
+#[test]
+fn from_token_stream_empty() {
+    let meta = Meta::from_token_stream(proc_macro2::TokenStream::new()).unwrap();
+    assert_eq!(meta.meta1, None);
+    assert!(!meta.meta2);
+}
+
+#[test]
+fn from_token_stream_invalid() {
+    let err = Meta::from_token_stream(quote::quote!(meta1 = "a" meta2)).unwrap_err();
+    assert!(err.has_span());
+}
+
+mod vec_list {
+    use darling::FromMeta;
+    use syn::parse_quote;
+
+    #[derive(Debug, FromMeta)]
+    struct Tags {
+        #[darling(default)]
+        tags: Vec<String>,
+        #[darling(multiple, rename = "tag")]
+        repeated: Vec<String>,
+    }
+
+    #[test]
+    fn list_form() {
+        let tags = Tags::from_list(&[parse_quote!(tags("a", "b"))]).unwrap();
+        assert_eq!(tags.tags, vec!["a".to_string(), "b".to_string()]);
+        assert!(tags.repeated.is_empty());
+    }
+
+    #[test]
+    fn empty_list() {
+        let tags = Tags::from_list(&[parse_quote!(tags())]).unwrap();
+        assert!(tags.tags.is_empty());
+    }
+
+    #[test]
+    fn repeated_form() {
+        let tags = Tags::from_list(&[parse_quote!(tag = "a"), parse_quote!(tag = "b")]).unwrap();
+        assert!(tags.tags.is_empty());
+        assert_eq!(tags.repeated, vec!["a".to_string(), "b".to_string()]);
+    }
+}
+
+mod lit_str {
+    use darling::{FromDeriveInput, FromMeta};
+
+    #[derive(FromMeta)]
+    struct Doc {
+        text: syn::LitStr,
+    }
+
+    #[derive(FromDeriveInput)]
+    #[darling(attributes(doc_it))]
+    struct Receiver {
+        doc: Doc,
+    }
+
+    #[test]
+    fn keeps_suffix() {
+        let di: syn::DeriveInput =
+            syn::parse_str("#[doc_it(doc(text = \"hello\"_ext))]\nstruct Foo;").unwrap();
+        let text = Receiver::from_derive_input(&di).unwrap().doc.text;
+
+        assert_eq!(text.value(), "hello");
+        assert_eq!(text.suffix(), "_ext");
+    }
+
+    #[test]
+    fn rejects_other_literals() {
+        let err = syn::LitStr::from_meta(&syn::parse_quote!(text = 5)).unwrap_err();
+        assert_eq!(err.to_string(), "Unexpected type `int`");
+    }
+}
@@ -0,0 +1,95 @@
+//! Tests for `#[darling(from_string = ...)]`, which gives a struct a string shorthand
+//! alongside its structured form.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(from_string = Size::parse)]
+struct Size {
+    width: u32,
+    height: u32,
+}
+
+impl Size {
+    fn parse(value: &str) -> darling::Result<Self> {
+        let (width, height) = value
+            .split_once('x')
+            .ok_or_else(|| darling::Error::custom("expected `WIDTHxHEIGHT`"))?;
+        Ok(Size {
+            width: width.parse().map_err(darling::Error::custom)?,
+            height: height.parse().map_err(darling::Error::custom)?,
+        })
+    }
+}
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(image))]
+struct Receiver {
+    size: Size,
+}
+
+fn size_of(input: syn::DeriveInput) -> darling::Result<Size> {
+    Receiver::from_derive_input(&input).map(|receiver| receiver.size)
+}
+
+#[test]
+fn string_and_structured_forms_match() {
+    let expected = Size {
+        width: 100,
+        height: 200,
+    };
+
+    let from_value = size_of(parse_quote! {
+        #[image(size = "100x200")]
+        struct Foo;
+    })
+    .unwrap();
+    let from_list = size_of(parse_quote! {
+        #[image(size("100x200"))]
+        struct Foo;
+    })
+    .unwrap();
+    let structured = size_of(parse_quote! {
+        #[image(size(width = 100, height = 200))]
+        struct Foo;
+    })
+    .unwrap();
+
+    assert_eq!(from_value, expected);
+    assert_eq!(from_list, expected);
+    assert_eq!(structured, expected);
+}
+
+#[test]
+fn closure() {
+    #[derive(Debug, PartialEq, Eq, FromMeta)]
+    #[darling(from_string = |value| Ok(Label { text: value.to_uppercase() }))]
+    struct Label {
+        text: String,
+    }
+
+    assert_eq!(
+        Label::from_string("hello").unwrap(),
+        Label {
+            text: "HELLO".to_string()
+        }
+    );
+}
+
+#[test]
+fn string_error_is_spanned() {
+    let err = size_of(parse_quote! {
+        #[image(size = "100 by 200")]
+        struct Foo;
+    })
+    .unwrap_err();
+    assert_eq!(err.to_string(), "expected `WIDTHxHEIGHT` at size");
+    assert!(err.has_span());
+}
+
+#[test]
+fn other_literal_in_list_is_rejected() {
+    let err = Size::from_list(&[parse_quote!(100)]).unwrap_err();
+    assert!(err.to_string().contains("literal"));
+}
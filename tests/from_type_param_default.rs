@@ -51,3 +51,23 @@ fn expand_many() {
         assert!(lorem.default.is_some());
     }
 }
+
+#[test]
+fn default_type_captured() {
+    let di: DeriveInput = parse_quote! {
+        struct Baz<T = Vec<u8>>(T);
+    };
+
+    let lorem = Lorem::from_type_param(extract_type(&di.generics.params[0])).unwrap();
+    assert_eq!(lorem.default, Some(parse_quote!(Vec<u8>)));
+}
+
+#[test]
+fn no_default_type() {
+    let di: DeriveInput = parse_quote! {
+        struct Baz<T>(T);
+    };
+
+    let lorem = Lorem::from_type_param(extract_type(&di.generics.params[0])).unwrap();
+    assert_eq!(lorem.default, None);
+}
@@ -55,3 +55,17 @@ fn discriminant() {
         panic!("Data should be enum");
     }
 }
+
+#[test]
+fn discriminant_expression() {
+    let variant: syn::Variant = syn::parse_quote!(Red = 1 << 2);
+    let lorem = Lorem::from_variant(&variant).unwrap();
+    assert_eq!(lorem.discriminant, Some(syn::parse_quote!(1 << 2)));
+}
+
+#[test]
+fn no_discriminant() {
+    let variant: syn::Variant = syn::parse_quote!(Red);
+    let lorem = Lorem::from_variant(&variant).unwrap();
+    assert_eq!(lorem.discriminant, None);
+}
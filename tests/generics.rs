@@ -21,3 +21,47 @@ fn expansion() {
 
     Foo::<String>::from_derive_input(&di).unwrap();
 }
+
+#[derive(Debug, FromMeta, PartialEq, Eq)]
+struct Named<T> {
+    value: T,
+}
+
+#[test]
+fn named_generic() {
+    assert_eq!(
+        Named::<u32>::from_list(&[parse_quote!(value = 8)]).unwrap(),
+        Named { value: 8 }
+    );
+    assert_eq!(
+        Named::<String>::from_meta(&parse_quote!(named(value = "eight"))).unwrap(),
+        Named {
+            value: "eight".to_string()
+        }
+    );
+}
+
+/// A type that doesn't implement `FromMeta`.
+#[derive(Debug)]
+struct Opaque;
+
+/// Only type parameters used by parsed fields are bounded by `FromMeta`, so `M` can be
+/// any type; lifetimes are carried through to the impl.
+#[derive(Debug, FromMeta)]
+struct Tagged<'a, T, M> {
+    value: T,
+    #[darling(skip)]
+    marker: std::marker::PhantomData<&'a M>,
+}
+
+#[test]
+fn unused_params_unbounded() {
+    let tagged = Tagged::<'static, u32, Opaque>::from_list(&[parse_quote!(value = 3)]).unwrap();
+    assert_eq!(tagged.value, 3);
+}
+
+#[test]
+fn generic_errors_have_location() {
+    let err = Named::<u32>::from_list(&[parse_quote!(value = "three")]).unwrap_err();
+    assert_eq!(err.to_string(), "Unknown value: `three` at value");
+}
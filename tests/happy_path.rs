@@ -67,3 +67,30 @@ fn trait_type() {
         }
     );
 }
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(darling_demo))]
+struct Verbosity {
+    #[darling(default)]
+    verbose: bool,
+}
+
+#[test]
+fn bool_word_form() {
+    let di = parse_quote! {
+        #[darling_demo(verbose)]
+        pub struct Bar;
+    };
+
+    assert!(Verbosity::from_derive_input(&di).unwrap().verbose);
+}
+
+#[test]
+fn bool_name_value_form() {
+    let di = parse_quote! {
+        #[darling_demo(verbose = false)]
+        pub struct Bar;
+    };
+
+    assert!(!Verbosity::from_derive_input(&di).unwrap().verbose);
+}
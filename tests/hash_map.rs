@@ -40,3 +40,36 @@ fn parse_map() {
 
     assert_eq!(comparison, map);
 }
+
+#[derive(Debug, FromMeta)]
+struct Config {
+    env: HashMap<String, String>,
+}
+
+#[test]
+fn parse_string_map_field() {
+    let attr: Attribute = parse_quote! {
+        #[config(env(FOO = "bar", BAZ = "qux"))]
+    };
+
+    let config = Config::from_meta(&attr.meta).unwrap();
+
+    assert_eq!(config.env.len(), 2);
+    assert_eq!(config.env["FOO"], "bar");
+    assert_eq!(config.env["BAZ"], "qux");
+}
+
+#[test]
+fn duplicate_keys_report_every_occurrence() {
+    let attr: Attribute = parse_quote! {
+        #[config(env(FOO = "bar", FOO = "baz", FOO = "qux"))]
+    };
+
+    let err = Config::from_meta(&attr.meta).unwrap_err();
+
+    assert_eq!(err.len(), 2);
+    for error in err.flatten() {
+        assert!(error.has_span());
+        assert_eq!(error.to_string(), "Duplicate field `FOO` at env");
+    }
+}
@@ -0,0 +1,29 @@
+use darling::{util::Maybe, FromDeriveInput};
+use syn::parse_quote;
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(sample))]
+struct Receiver {
+    rename: Maybe<Option<String>>,
+}
+
+#[test]
+fn absent() {
+    let receiver = Receiver::from_derive_input(&parse_quote! {
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(receiver.rename, Maybe::Absent);
+}
+
+#[test]
+fn present() {
+    let receiver = Receiver::from_derive_input(&parse_quote! {
+        #[sample(rename = "other")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(receiver.rename, Maybe::Present(Some("other".to_string())));
+}
@@ -48,3 +48,74 @@ fn handles_all_cases() {
     assert_eq!(input.example3, parse_quote!(example3));
     assert_eq!(input.example4, "world".to_string());
 }
+
+#[derive(FromMeta)]
+struct Table {
+    #[darling(with = darling::util::parse_comma_list)]
+    columns: Vec<String>,
+}
+
+#[test]
+fn comma_list() {
+    let table = Table::from_list(&[parse_quote!(columns = "id, name , email")]).unwrap();
+    assert_eq!(table.columns, vec!["id", "name", "email"]);
+}
+
+/// The function passed to `with` receives the whole `syn::Meta`, so it can tell which of
+/// a field's names was used.
+fn level_from_meta(meta: &syn::Meta) -> darling::Result<u8> {
+    if meta.path().is_ident("loud") {
+        Ok(u8::from_meta(meta)? * 10)
+    } else {
+        u8::from_meta(meta)
+    }
+}
+
+#[derive(FromMeta)]
+struct Volume {
+    #[darling(alias = "loud", with = level_from_meta)]
+    level: u8,
+}
+
+#[test]
+fn with_inspects_path() {
+    let quiet = Volume::from_list(&[parse_quote!(level = 3)]).unwrap();
+    assert_eq!(quiet.level, 3);
+
+    let loud = Volume::from_list(&[parse_quote!(loud = 3)]).unwrap();
+    assert_eq!(loud.level, 30);
+}
+
+fn parse_even(meta: &syn::Meta) -> darling::Result<u8> {
+    let value = u8::from_meta(meta)?;
+    if value % 2 == 0 {
+        Ok(value)
+    } else {
+        Err(darling::Error::custom("expected an even number"))
+    }
+}
+
+#[derive(Debug, FromMeta)]
+#[allow(dead_code)]
+struct Even {
+    #[darling(with = parse_even)]
+    width: u8,
+    #[darling(with = parse_even, multiple)]
+    steps: Vec<u8>,
+}
+
+#[test]
+fn with_errors_include_field_location() {
+    let err = Even::from_list(&[
+        parse_quote!(width = 3),
+        parse_quote!(steps = 2),
+        parse_quote!(steps = 5),
+    ])
+    .unwrap_err();
+
+    let errors = err.into_iter().collect::<Vec<_>>();
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().all(darling::Error::has_span));
+    assert_eq!(errors[0].to_string(), "expected an even number at width");
+    assert_eq!(errors[1].to_string(), "expected an even number at steps[1]");
+}
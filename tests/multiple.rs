@@ -1,3 +1,5 @@
+use std::collections::{BTreeSet, HashSet};
+
 use darling::{FromDeriveInput, FromMeta};
 use syn::parse_quote;
 
@@ -28,3 +30,77 @@ fn expand_many() {
         vec!["Hello".to_string(), "World".to_string()]
     );
 }
+
+#[derive(Debug, FromMeta)]
+struct Flags {
+    #[darling(multiple)]
+    hash: HashSet<String>,
+    #[darling(multiple, rename = "ordered")]
+    btree: BTreeSet<u8>,
+    #[darling(multiple, dedup = "error", default)]
+    strict: BTreeSet<String>,
+    #[darling(multiple, dedup = "ignore", default)]
+    list: Vec<u8>,
+}
+
+#[test]
+fn collect_into_sets() {
+    let flags = Flags::from_list(&[
+        parse_quote!(hash = "a"),
+        parse_quote!(hash = "b"),
+        parse_quote!(ordered = 3),
+        parse_quote!(ordered = 1),
+    ])
+    .unwrap();
+
+    assert_eq!(
+        flags.hash,
+        HashSet::from(["a".to_string(), "b".to_string()])
+    );
+    assert_eq!(flags.btree.into_iter().collect::<Vec<_>>(), vec![1, 3]);
+}
+
+#[test]
+fn sets_ignore_duplicates_by_default() {
+    let flags = Flags::from_list(&[
+        parse_quote!(hash = "a"),
+        parse_quote!(hash = "a"),
+        parse_quote!(ordered = 1),
+        parse_quote!(ordered = 1),
+    ])
+    .unwrap();
+
+    assert_eq!(flags.hash.len(), 1);
+    assert_eq!(flags.btree.len(), 1);
+}
+
+#[test]
+fn dedup_ignore_on_vec() {
+    let flags = Flags::from_list(&[
+        parse_quote!(hash = "a"),
+        parse_quote!(ordered = 1),
+        parse_quote!(list = 2),
+        parse_quote!(list = 1),
+        parse_quote!(list = 2),
+    ])
+    .unwrap();
+
+    assert!(flags.strict.is_empty());
+    assert_eq!(flags.list, vec![2, 1]);
+}
+
+#[test]
+fn dedup_error() {
+    let err = Flags::from_list(&[
+        parse_quote!(hash = "a"),
+        parse_quote!(ordered = 1),
+        parse_quote!(strict = "x"),
+        parse_quote!(strict = "y"),
+        parse_quote!(strict = "x"),
+    ])
+    .unwrap_err();
+
+    assert_eq!(err.len(), 1);
+    assert_eq!(err.to_string(), "duplicate value for `strict`");
+    assert!(err.has_span());
+}
@@ -0,0 +1,53 @@
+//! Test that multi-segment paths in `#[darling(attributes(...))]` only match
+//! attributes with the same full path.
+
+use darling::{FromAttributes, FromDeriveInput};
+use syn::parse_quote;
+
+#[derive(Debug, Default, FromAttributes)]
+#[darling(attributes(my_macro::inner))]
+struct Inner {
+    value: Option<u8>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(my_macro::outer))]
+struct Outer {
+    name: String,
+}
+
+#[test]
+fn matching_namespaced_attribute() {
+    let attrs: Vec<syn::Attribute> = vec![
+        parse_quote!(#[doc = "Some docs"]),
+        parse_quote!(#[my_macro::inner(value = 3)]),
+    ];
+
+    assert_eq!(Inner::from_attributes(&attrs).unwrap().value, Some(3));
+}
+
+#[test]
+fn non_matching_attributes_ignored() {
+    let attrs: Vec<syn::Attribute> = vec![
+        parse_quote!(#[doc = "Some docs"]),
+        parse_quote!(#[inner(value = 1)]),
+        parse_quote!(#[my_macro(value = 2)]),
+        parse_quote!(#[other::inner(value = 3)]),
+        parse_quote!(#[my_macro::inner::deeper(value = 4)]),
+    ];
+
+    assert_eq!(Inner::from_attributes(&attrs).unwrap().value, None);
+}
+
+#[test]
+fn from_derive_input() {
+    let outer = Outer::from_derive_input(&parse_quote! {
+        /// Some docs
+        #[outer(name = "ignored")]
+        #[my_macro::outer(name = "used")]
+        struct Foo;
+    })
+    .unwrap();
+
+    assert_eq!(outer.name, "used");
+}
@@ -0,0 +1,62 @@
+use darling::{util::OneOf2, FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+struct Remote {
+    url: String,
+}
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(asset))]
+struct Receiver {
+    source: OneOf2<String, Remote>,
+}
+
+fn parse(input: syn::DeriveInput) -> darling::Result<OneOf2<String, Remote>> {
+    Receiver::from_derive_input(&input).map(|receiver| receiver.source)
+}
+
+#[test]
+fn string_form() {
+    assert_eq!(
+        parse(parse_quote! {
+            #[asset(source = "file.txt")]
+            struct Demo;
+        })
+        .unwrap(),
+        OneOf2::First("file.txt".to_string())
+    );
+}
+
+#[test]
+fn struct_form() {
+    assert_eq!(
+        parse(parse_quote! {
+            #[asset(source(url = "https://example.com"))]
+            struct Demo;
+        })
+        .unwrap(),
+        OneOf2::Second(Remote {
+            url: "https://example.com".to_string()
+        })
+    );
+}
+
+#[test]
+fn both_fail() {
+    let err = parse(parse_quote! {
+        #[asset(source(uri = "https://example.com"))]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    let messages = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            "Unexpected meta-item format `list`",
+            "Unknown field: `uri`. Did you mean `url`?",
+            "Missing field `url`",
+        ]
+    );
+}
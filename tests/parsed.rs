@@ -0,0 +1,55 @@
+use darling::{util::Parsed, FromDeriveInput};
+use syn::parse_quote;
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(sample))]
+struct Receiver {
+    name: String,
+    limit: Parsed<u8>,
+    label: Parsed<Option<String>>,
+}
+
+#[test]
+fn valid_inner_parse() {
+    let receiver = Receiver::from_derive_input(&parse_quote! {
+        #[sample(name = "demo", limit = 5, label = "x")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(receiver.name, "demo");
+    assert_eq!(*receiver.limit.as_result().unwrap(), 5);
+    assert_eq!(receiver.label.into_result().unwrap(), Some("x".to_string()));
+}
+
+#[test]
+fn invalid_inner_parse() {
+    let receiver = Receiver::from_derive_input(&parse_quote! {
+        #[sample(name = "demo", limit = 500, label = 7)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(receiver.name, "demo");
+    assert!(receiver.limit.is_invalid());
+    assert!(receiver.label.is_invalid());
+
+    let limit = receiver.limit.into_result().unwrap_err();
+    assert!(limit.has_span());
+    assert!(limit.to_string().ends_with(" at limit"));
+
+    let label = receiver.label.into_result().unwrap_err();
+    assert!(label.has_span());
+    assert!(label.to_string().ends_with(" at label"));
+}
+
+#[test]
+fn missing_optional_inner() {
+    let receiver = Receiver::from_derive_input(&parse_quote! {
+        #[sample(name = "demo", limit = 1)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(receiver.label.into_result().unwrap(), None);
+}
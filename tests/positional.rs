@@ -0,0 +1,121 @@
+//! Tests for `#[darling(positional)]` fields, which are read from leading list items
+//! before any named items.
+
+use darling::FromMeta;
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(derive_syn_parse)]
+struct Route {
+    #[darling(positional)]
+    path: String,
+    #[darling(default)]
+    method: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+struct Span {
+    #[darling(positional)]
+    start: u32,
+    #[darling(positional, map = "double")]
+    end: u32,
+    #[darling(default)]
+    inclusive: bool,
+}
+
+fn double(value: u32) -> u32 {
+    value * 2
+}
+
+#[test]
+fn positional_then_named() {
+    let route: Route = parse_quote!("/path", method = "GET");
+    assert_eq!(
+        route,
+        Route {
+            path: "/path".to_string(),
+            method: Some("GET".to_string()),
+        }
+    );
+}
+
+#[test]
+fn positional_only() {
+    let route: Route = parse_quote!("/path");
+    assert_eq!(route.path, "/path");
+    assert_eq!(route.method, None);
+}
+
+#[test]
+fn several_positionals() {
+    let span =
+        Span::from_list(&[parse_quote!(1), parse_quote!(2), parse_quote!(inclusive)]).unwrap();
+    assert_eq!(
+        span,
+        Span {
+            start: 1,
+            end: 4,
+            inclusive: true,
+        }
+    );
+}
+
+#[test]
+fn word_flag_after_positional() {
+    let err = Span::from_list(&[parse_quote!(1), parse_quote!(inclusive)]).unwrap_err();
+    assert_eq!(err.to_string(), "Missing field `end`");
+}
+
+#[test]
+fn word_flag_without_positional() {
+    #[derive(Debug, FromMeta)]
+    #[allow(dead_code)]
+    struct StrictRoute {
+        #[darling(positional)]
+        path: String,
+        #[darling(default)]
+        strict: bool,
+    }
+
+    let err = StrictRoute::from_list(&[parse_quote!(strict)]).unwrap_err();
+    assert_eq!(err.to_string(), "Missing field `path`");
+
+    let err = StrictRoute::from_list(&[parse_quote!(strict), parse_quote!("/path")])
+        .unwrap_err()
+        .flatten();
+    let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+    assert!(messages.contains(&"positional values must come before named items".to_string()));
+}
+
+#[test]
+fn missing_positional() {
+    let err = Route::from_list(&[parse_quote!(method = "GET")]).unwrap_err();
+    assert_eq!(err.to_string(), "Missing field `path`");
+}
+
+#[test]
+fn positional_after_named() {
+    let err = Route::from_list(&[parse_quote!(method = "GET"), parse_quote!("/path")])
+        .unwrap_err()
+        .flatten();
+    let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+    assert!(messages.contains(&"positional values must come before named items".to_string()));
+}
+
+#[test]
+fn extra_positional() {
+    let err = Route::from_list(&[
+        parse_quote!("/path"),
+        parse_quote!("/other"),
+        parse_quote!(method = "GET"),
+    ])
+    .unwrap_err();
+    assert_eq!(err.to_string(), "expected at most 1 positional value");
+}
+
+#[test]
+fn named_positional_is_unknown() {
+    let err =
+        Route::from_list(&[parse_quote!("/path"), parse_quote!(path = "/other")]).unwrap_err();
+    assert!(err.to_string().starts_with("Unknown field: `path`"));
+}
@@ -0,0 +1,43 @@
+use darling::{util::Provenance, FromDeriveInput};
+use syn::parse_quote;
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(sample))]
+struct Receiver {
+    #[darling(default)]
+    level: Provenance<u8>,
+    #[darling(default = || Provenance::defaulted("info".to_string()))]
+    target: Provenance<String>,
+    rename: Provenance<Option<String>>,
+}
+
+#[test]
+fn explicit() {
+    let receiver = Receiver::from_derive_input(&parse_quote! {
+        #[sample(level = 0, target = "debug", rename = "other")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert!(receiver.level.is_explicit());
+    assert_eq!(*receiver.level, 0);
+    assert!(receiver.target.is_explicit());
+    assert_eq!(*receiver.target, "debug");
+    assert!(receiver.rename.is_explicit());
+    assert_eq!(*receiver.rename, Some("other".to_string()));
+}
+
+#[test]
+fn defaulted() {
+    let receiver = Receiver::from_derive_input(&parse_quote! {
+        struct Demo;
+    })
+    .unwrap();
+
+    assert!(receiver.level.is_default());
+    assert_eq!(*receiver.level, 0);
+    assert!(receiver.target.is_default());
+    assert_eq!(*receiver.target, "info");
+    assert!(receiver.rename.is_default());
+    assert_eq!(*receiver.rename, None);
+}
@@ -0,0 +1,140 @@
+//! Test that container-level `rename_all` is applied to field names, and that
+//! field-level `rename` takes precedence over it.
+
+use darling::{FromDeriveInput, FromField, FromMeta, FromVariant};
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta, PartialEq, Eq)]
+#[darling(rename_all = "camelCase")]
+struct Camel {
+    max_retry_count: u8,
+    #[darling(rename = "explicit_name")]
+    renamed_field: bool,
+}
+
+#[derive(Debug, FromMeta, PartialEq, Eq)]
+#[darling(rename_all = "PascalCase")]
+struct Pascal {
+    max_retry_count: u8,
+}
+
+#[derive(Debug, FromMeta, PartialEq, Eq)]
+#[darling(rename_all = "SCREAMING_SNAKE_CASE")]
+struct ScreamingSnake {
+    max_retry_count: u8,
+}
+
+#[derive(Debug, FromMeta, PartialEq, Eq)]
+#[darling(rename_all = "snake_case")]
+struct Snake {
+    max_retry_count: u8,
+}
+
+#[test]
+fn explicit_rename_wins() {
+    let err = Camel::from_meta(&parse_quote!(ignore(maxRetryCount = 3, renamedField)))
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("renamedField"));
+}
+
+#[test]
+fn camel_case() {
+    assert_eq!(
+        Camel::from_meta(&parse_quote!(ignore(maxRetryCount = 3, explicit_name))).unwrap(),
+        Camel {
+            max_retry_count: 3,
+            renamed_field: true,
+        }
+    );
+}
+
+#[test]
+fn pascal_case() {
+    assert_eq!(
+        Pascal::from_meta(&parse_quote!(ignore(MaxRetryCount = 3))).unwrap(),
+        Pascal { max_retry_count: 3 }
+    );
+}
+
+#[test]
+fn screaming_snake_case() {
+    assert_eq!(
+        ScreamingSnake::from_meta(&parse_quote!(ignore(MAX_RETRY_COUNT = 3))).unwrap(),
+        ScreamingSnake { max_retry_count: 3 }
+    );
+}
+
+#[test]
+fn snake_case() {
+    assert_eq!(
+        Snake::from_meta(&parse_quote!(ignore(max_retry_count = 3))).unwrap(),
+        Snake { max_retry_count: 3 }
+    );
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(retry), rename_all = "PascalCase")]
+struct RetryReceiver {
+    max_retry_count: u8,
+}
+
+#[test]
+fn from_derive_input() {
+    let di: syn::DeriveInput = parse_quote! {
+        #[retry(MaxRetryCount = 4)]
+        struct Example;
+    };
+
+    assert_eq!(
+        RetryReceiver::from_derive_input(&di)
+            .unwrap()
+            .max_retry_count,
+        4
+    );
+}
+
+#[derive(Debug, FromField)]
+#[darling(attributes(retry), rename_all = "camelCase")]
+struct RetryField {
+    max_retry_count: u8,
+}
+
+#[test]
+fn from_field() {
+    let di: syn::DeriveInput = parse_quote! {
+        struct Example {
+            #[retry(maxRetryCount = 5)]
+            field: u8,
+        }
+    };
+
+    let field = match di.data {
+        syn::Data::Struct(data) => data.fields.into_iter().next().unwrap(),
+        _ => unreachable!(),
+    };
+
+    assert_eq!(RetryField::from_field(&field).unwrap().max_retry_count, 5);
+}
+
+#[derive(Debug, FromVariant)]
+#[darling(attributes(retry), rename_all = "SCREAMING_SNAKE_CASE")]
+struct RetryVariant {
+    max_retry_count: u8,
+}
+
+#[test]
+fn from_variant() {
+    let variant: syn::Variant = parse_quote! {
+        #[retry(MAX_RETRY_COUNT = 6)]
+        Example
+    };
+
+    assert_eq!(
+        RetryVariant::from_variant(&variant)
+            .unwrap()
+            .max_retry_count,
+        6
+    );
+}
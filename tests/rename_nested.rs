@@ -0,0 +1,56 @@
+//! A renamed field whose type is itself a struct still parses its nested list.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+struct Bounds {
+    min: i64,
+    max: i64,
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+struct Validator {
+    #[darling(rename = "range")]
+    bounds: Bounds,
+    #[darling(rename = "limit", default)]
+    max_len: Option<Bounds>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(check))]
+struct Receiver {
+    validator: Validator,
+}
+
+#[test]
+fn renamed_nested_struct() {
+    let receiver = Receiver::from_derive_input(&parse_quote! {
+        #[check(validator(range(min = 0, max = 10), limit(min = 1, max = 3)))]
+        struct Foo;
+    })
+    .unwrap();
+
+    assert_eq!(
+        receiver.validator,
+        Validator {
+            bounds: Bounds { min: 0, max: 10 },
+            max_len: Some(Bounds { min: 1, max: 3 }),
+        }
+    );
+}
+
+#[test]
+fn renamed_nested_struct_rust_name_rejected() {
+    let err = Validator::from_list(&[parse_quote!(bounds(min = 0, max = 10))]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Multiple errors: (Unknown field: `bounds`. Available values: `limit`, `range`, Missing field `range`)"
+    );
+}
+
+#[test]
+fn renamed_nested_struct_error_location() {
+    let err = Validator::from_list(&[parse_quote!(range(min = 0))]).unwrap_err();
+    assert_eq!(err.to_string(), "Missing field `max` at range");
+}
@@ -0,0 +1,93 @@
+//! Test that a `rest` field collects otherwise-unknown meta items by name.
+
+use std::collections::HashMap;
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(sample))]
+struct Example {
+    label: String,
+    #[darling(rest)]
+    extra: HashMap<String, syn::Meta>,
+}
+
+/// A newtype that keeps unknown items in the order they were written.
+#[derive(Debug, Default)]
+struct Ordered(Vec<String>);
+
+impl FromIterator<(String, syn::Meta)> for Ordered {
+    fn from_iter<I: IntoIterator<Item = (String, syn::Meta)>>(iter: I) -> Self {
+        Ordered(iter.into_iter().map(|(name, _)| name).collect())
+    }
+}
+
+#[derive(FromMeta)]
+struct Nested {
+    known: bool,
+    #[darling(rest)]
+    rest: Ordered,
+}
+
+#[test]
+fn collects_unknown_items() {
+    let parsed = Example::from_derive_input(&parse_quote! {
+        #[sample(label = "Hello", color = "red", size(large), verbose)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(parsed.label, "Hello");
+    assert_eq!(parsed.extra.len(), 3);
+    assert_eq!(parsed.extra["color"], parse_quote!(color = "red"));
+    assert_eq!(parsed.extra["size"], parse_quote!(size(large)));
+    assert_eq!(parsed.extra["verbose"], parse_quote!(verbose));
+}
+
+#[test]
+fn empty_when_no_unknown_items() {
+    let parsed = Example::from_derive_input(&parse_quote! {
+        #[sample(label = "Hello")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert!(parsed.extra.is_empty());
+}
+
+#[test]
+fn known_fields_still_validated() {
+    let err = Example::from_derive_input(&parse_quote! {
+        #[sample(color = "red")]
+        struct Demo;
+    })
+    .map(|_| "Should have failed")
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "Missing field `label`");
+}
+
+#[test]
+fn newtype_rest() {
+    let parsed = Nested::from_list(
+        &darling::ast::NestedMeta::parse_meta_list(quote::quote!(zeta, known, alpha = 1)).unwrap(),
+    )
+    .unwrap();
+
+    assert!(parsed.known);
+    assert_eq!(parsed.rest.0, vec!["zeta".to_string(), "alpha".to_string()]);
+}
+
+#[test]
+fn duplicate_unknown_item() {
+    let err = Example::from_derive_input(&parse_quote! {
+        #[sample(label = "Hello", color = "red", color = "blue")]
+        struct Demo;
+    })
+    .map(|_| "Should have failed")
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "Duplicate field `color`");
+    assert!(err.has_span());
+}
@@ -72,3 +72,70 @@ fn verify_default_supersedes_from_none() {
         Defaulting { dolor: Some(2) }
     )
 }
+
+/// A skipped field with an explicit default doesn't need its type to implement `Default`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Label(String);
+
+fn default_label() -> Label {
+    Label("unnamed".to_string())
+}
+
+#[derive(Debug, PartialEq, Eq, FromDeriveInput)]
+#[darling(attributes(skip_test))]
+pub struct Labelled {
+    ipsum: String,
+    #[darling(skip, default = default_label)]
+    label: Label,
+    #[darling(skip, default = || Label("closure".to_string()))]
+    other: Label,
+}
+
+#[test]
+fn skipped_field_non_default_type() {
+    let di = parse_quote! {
+        #[skip_test(ipsum = "Hello")]
+        struct Baz;
+    };
+
+    assert_eq!(
+        Labelled::from_derive_input(&di).unwrap(),
+        Labelled {
+            ipsum: "Hello".to_string(),
+            label: Label("unnamed".to_string()),
+            other: Label("closure".to_string()),
+        }
+    );
+}
+
+/// A skipped field which depends on other fields is filled in by the container's `and_then`,
+/// which runs once every field has been parsed.
+#[derive(Debug, PartialEq, Eq, FromDeriveInput)]
+#[darling(attributes(skip_test), and_then = Self::compute_label)]
+pub struct Computed {
+    ipsum: String,
+    #[darling(skip, default = default_label)]
+    label: Label,
+}
+
+impl Computed {
+    fn compute_label(self) -> darling::Result<Self> {
+        Ok(Self {
+            label: Label(format!("{}-label", self.ipsum)),
+            ..self
+        })
+    }
+}
+
+#[test]
+fn skipped_field_computed_from_others() {
+    let di = parse_quote! {
+        #[skip_test(ipsum = "Hello")]
+        struct Baz;
+    };
+
+    assert_eq!(
+        Computed::from_derive_input(&di).unwrap().label,
+        Label("Hello-label".to_string())
+    );
+}
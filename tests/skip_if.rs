@@ -0,0 +1,146 @@
+//! Test `#[darling(skip_if = ...)]`, which decides at parse time whether a field is skipped.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+fn enabled() -> bool {
+    true
+}
+
+fn disabled() -> bool {
+    false
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo))]
+struct Receiver {
+    name: String,
+    #[darling(skip_if = enabled)]
+    unavailable: u32,
+    #[darling(skip_if = disabled)]
+    available: u32,
+    #[darling(skip_if = disabled)]
+    optional: Option<String>,
+}
+
+#[test]
+fn skipped_field_uses_default() {
+    let di = parse_quote! {
+        #[demo(name = "hello", available = 5)]
+        struct Foo;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name, "hello");
+    assert_eq!(receiver.unavailable, 0);
+    assert_eq!(receiver.available, 5);
+    assert_eq!(receiver.optional, None);
+}
+
+#[test]
+fn skipped_field_present_is_error() {
+    let di = parse_quote! {
+        #[demo(name = "hello", unavailable = 2, available = 5)]
+        struct Foo;
+    };
+
+    let error = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert!(error
+        .to_string()
+        .contains("field `unavailable` is not available"));
+}
+
+#[test]
+fn not_skipped_field_is_parsed() {
+    let di = parse_quote! {
+        #[demo(name = "hello", available = 5, optional = "x")]
+        struct Foo;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.optional.as_deref(), Some("x"));
+}
+
+#[test]
+fn not_skipped_field_is_required() {
+    let di = parse_quote! {
+        #[demo(name = "hello")]
+        struct Foo;
+    };
+
+    let error = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert!(error.to_string().contains("Missing field `available`"));
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo))]
+struct WithDefault {
+    #[darling(skip_if = || true, default = || 7)]
+    value: u32,
+}
+
+#[test]
+fn skipped_field_uses_explicit_default() {
+    let di = parse_quote! {
+        #[demo]
+        struct Foo;
+    };
+
+    assert_eq!(WithDefault::from_derive_input(&di).unwrap().value, 7);
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Level(u8);
+
+impl FromMeta for Level {
+    fn from_none() -> Option<Self> {
+        Some(Level(3))
+    }
+
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        u8::from_value(value).map(Level)
+    }
+}
+
+#[derive(Debug, Default, FromDeriveInput)]
+#[darling(attributes(demo), default)]
+struct WithContainerDefault {
+    #[darling(skip_if = || true)]
+    value: u32,
+    #[darling(skip_if = || true)]
+    level: Level,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo))]
+struct WithFromNone {
+    #[darling(skip_if = || true)]
+    level: Level,
+}
+
+#[test]
+fn skipped_field_uses_container_default() {
+    let di = parse_quote! {
+        #[demo]
+        struct Foo;
+    };
+
+    let parsed = WithContainerDefault::from_derive_input(&di).unwrap();
+    assert_eq!(parsed.value, 0);
+    assert_eq!(parsed.level, Level(0));
+}
+
+#[test]
+fn skipped_field_prefers_from_none() {
+    let di = parse_quote! {
+        #[demo]
+        struct Foo;
+    };
+
+    assert_eq!(
+        WithFromNone::from_derive_input(&di).unwrap().level,
+        Level(3)
+    );
+}
@@ -42,3 +42,35 @@ fn dont_suggest_skipped_field() {
     assert_eq!(1, result.len());
     assert!(!format!("{}", result).contains("amet"));
 }
+
+#[derive(Debug, FromMeta)]
+enum Mode {
+    Fast,
+    Slow,
+    Balanced(bool),
+}
+
+#[test]
+fn suggest_closest_variant() {
+    let err = Mode::from_string("balancd").unwrap_err().to_string();
+    assert!(err.contains("Did you mean `balanced`?"), "{}", err);
+}
+
+#[test]
+fn list_variants_when_nothing_is_close() {
+    let err = Mode::from_string("turbo").unwrap_err().to_string();
+    assert!(!err.contains("Did you mean"), "{}", err);
+    assert!(
+        err.contains("Available values: `balanced`, `fast`, `slow`"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn list_variants_for_unknown_list_item() {
+    let err = Mode::from_meta(&parse_quote!(mode(turbo(level = 3))))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("`balanced`, `fast`, `slow`"), "{}", err);
+}
@@ -115,3 +115,39 @@ fn struct_named() {
     StructContainer::from_derive_input(&source::newtype_enum()).unwrap_err();
     StructContainer::from_derive_input(&source::empty_enum()).unwrap_err();
 }
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(supports(struct_named, struct_newtype))]
+pub struct NamedOrNewtype {
+    data: ast::Data<Panic, syn::Field>,
+}
+
+#[test]
+fn combined_struct_shapes() {
+    // Should pass
+    let named = NamedOrNewtype::from_derive_input(&source::named_struct()).unwrap();
+    assert!(named.data.is_struct());
+    let newtype = NamedOrNewtype::from_derive_input(&syn::parse_quote!(
+        struct Hello(String);
+    ))
+    .unwrap();
+    assert!(newtype.data.is_struct());
+
+    // Should fail
+    let err = NamedOrNewtype::from_derive_input(&source::tuple_struct()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unsupported shape `unnamed fields`. Expected named fields or one unnamed field."
+    );
+    assert!(err.has_span());
+}
+
+#[test]
+fn struct_named_error_message() {
+    let err = StructContainer::from_derive_input(&source::newtype_enum()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unsupported shape `enum`. Expected struct with named fields."
+    );
+    assert!(err.has_span());
+}
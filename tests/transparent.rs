@@ -46,3 +46,88 @@ fn generated() {
     assert_eq!(c2.inner.lorem, Lorem(false));
     assert_eq!(c2.inner.lorem_2, Lorem2 { named: false });
 }
+
+#[derive(Debug, FromMeta, PartialEq, Eq)]
+#[darling(transparent)]
+struct Port(u16);
+
+#[derive(Debug, FromMeta, PartialEq, Eq)]
+#[darling(transparent)]
+struct Host {
+    name: String,
+}
+
+#[derive(Debug, FromMeta, PartialEq, Eq)]
+struct PortRange(Port, Port);
+
+#[test]
+fn explicit_transparent_newtype() {
+    assert_eq!(
+        Port::from_meta(&parse_quote!(port = 8080)).unwrap(),
+        Port(8080)
+    );
+    assert_eq!(
+        Port::from_nested_meta(&parse_quote!(8080)).unwrap(),
+        Port(8080)
+    );
+    assert_eq!(
+        PortRange::from_meta(&parse_quote!(ports(8080, 8090))).unwrap(),
+        PortRange(Port(8080), Port(8090))
+    );
+    assert_eq!(
+        Host::from_meta(&parse_quote!(host = "localhost")).unwrap(),
+        Host {
+            name: "localhost".to_string()
+        }
+    );
+}
+
+#[test]
+fn transparent_newtype_errors() {
+    let err = Port::from_meta(&parse_quote!(port = "eighty")).unwrap_err();
+    assert_eq!(err.to_string(), "Unknown value: `eighty`");
+}
+
+#[test]
+fn transparent_newtype_from_list() {
+    let items = darling::ast::NestedMeta::parse_meta_list(quote::quote!(8080)).unwrap();
+    assert_eq!(Port::from_list(&items).unwrap(), Port(8080));
+
+    let err = Port::from_list(&[]).unwrap_err();
+    assert_eq!(err.to_string(), "Too few items: Expected at least 1");
+
+    let items = darling::ast::NestedMeta::parse_meta_list(quote::quote!(8080, 8090)).unwrap();
+    let err = Port::from_list(&items).unwrap_err();
+    assert_eq!(err.to_string(), "Too many items: Expected no more than 1");
+    assert!(err.has_span());
+}
+
+#[test]
+fn transparent_newtype_from_meta_list() {
+    assert_eq!(
+        Port::from_meta(&parse_quote!(port(8080))).unwrap(),
+        Port(8080)
+    );
+
+    let err = Port::from_meta(&parse_quote!(port(8080, 8090))).unwrap_err();
+    assert_eq!(err.to_string(), "Too many items: Expected no more than 1");
+}
+
+#[derive(Debug, FromMeta, PartialEq, Eq)]
+#[darling(transparent)]
+struct Tags(Vec<String>);
+
+#[test]
+fn transparent_list_type() {
+    assert_eq!(
+        Tags::from_meta(&parse_quote!(tags("a", "b"))).unwrap(),
+        Tags(vec!["a".to_string(), "b".to_string()])
+    );
+
+    let items = darling::ast::NestedMeta::parse_meta_list(quote::quote!("a", "b")).unwrap();
+    assert_eq!(
+        Tags::from_list(&items).unwrap(),
+        Tags(vec!["a".to_string(), "b".to_string()])
+    );
+    assert_eq!(Tags::from_list(&[]).unwrap(), Tags(vec![]));
+}
@@ -0,0 +1,87 @@
+//! Tests for `FromMeta` on tuple structs, whose fields are read positionally.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(derive_syn_parse)]
+struct Range(u32, u32);
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(limits))]
+struct Receiver {
+    range: Range,
+}
+
+#[test]
+fn two_fields() {
+    let range = Range::from_list(&[parse_quote!(0), parse_quote!(100)]).unwrap();
+    assert_eq!(range, Range(0, 100));
+}
+
+#[test]
+fn nested_in_receiver() {
+    let receiver = Receiver::from_derive_input(&parse_quote! {
+        #[limits(range(5, 10))]
+        struct Foo;
+    })
+    .unwrap();
+
+    assert_eq!(receiver.range, Range(5, 10));
+}
+
+#[test]
+fn parse_impl() {
+    let range: Range = syn::parse_quote!(1, 2);
+    assert_eq!(range, Range(1, 2));
+}
+
+#[test]
+fn too_few_items() {
+    let err = Range::from_list(&[parse_quote!(0)]).unwrap_err();
+    assert_eq!(err.to_string(), "Too few items: Expected at least 2");
+    assert!(err.has_span());
+}
+
+#[test]
+fn too_many_items() {
+    let err = Range::from_list(&[parse_quote!(0), parse_quote!(1), parse_quote!(2)]).unwrap_err();
+    assert_eq!(err.to_string(), "Too many items: Expected no more than 2");
+    assert!(err.has_span());
+}
+
+#[test]
+fn no_items() {
+    let err = Range::from_list(&[]).unwrap_err();
+    assert_eq!(err.to_string(), "Too few items: Expected at least 2");
+}
+
+#[test]
+fn named_item() {
+    let err = Range::from_list(&[parse_quote!(0), parse_quote!(end = 100)]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected a positional value, found named item `end`"
+    );
+}
+
+#[test]
+fn all_items_named() {
+    let items =
+        darling::ast::NestedMeta::parse_meta_list(quote::quote!(a = 1, b = pub(crate))).unwrap();
+    let err = Range::from_list(&items).unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "expected a positional value, found named item `a`",
+            "expected a positional value, found named item `b`",
+        ]
+    );
+}
+
+#[test]
+fn bad_value() {
+    let err = Range::from_list(&[parse_quote!(0), parse_quote!("big")]).unwrap_err();
+    assert_eq!(err.len(), 1);
+}
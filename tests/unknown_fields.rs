@@ -0,0 +1,155 @@
+//! Test the `unknown_fields` container option, including overriding it on a flattened
+//! sub-struct.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+#[darling(unknown_fields = "deny")]
+#[allow(dead_code)]
+struct Deny {
+    known: bool,
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(unknown_fields = "allow")]
+struct Allow {
+    known: bool,
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(unknown_fields = "warn")]
+struct Warn {
+    known: bool,
+    #[darling(warnings)]
+    warnings: Vec<darling::Error>,
+}
+
+fn list(tokens: proc_macro2::TokenStream) -> Vec<darling::ast::NestedMeta> {
+    darling::ast::NestedMeta::parse_meta_list(tokens).unwrap()
+}
+
+#[test]
+fn deny() {
+    let err = Deny::from_list(&list(quote::quote!(known, extra))).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unknown field: `extra`. Available values: `known`"
+    );
+}
+
+#[test]
+fn allow() {
+    assert!(
+        Allow::from_list(&list(quote::quote!(known, extra)))
+            .unwrap()
+            .known
+    );
+}
+
+#[test]
+fn warn_still_parses() {
+    let parsed = Warn::from_list(&list(quote::quote!(known, extra, other = 1))).unwrap();
+    assert!(parsed.known);
+
+    let warnings = parsed.warnings;
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(
+        warnings[0].to_string(),
+        "Unknown field: `extra`. Available values: `known`"
+    );
+    assert!(warnings[0].has_span());
+    assert_eq!(
+        warnings[1].to_string(),
+        "Unknown field: `other`. Available values: `known`"
+    );
+}
+
+#[test]
+fn warn_still_reports_errors() {
+    let err = Warn::from_list(&list(quote::quote!(known = "yes", extra))).unwrap_err();
+    assert_eq!(err.len(), 1);
+}
+
+#[test]
+fn warn_without_unknown_fields() {
+    let parsed = Warn::from_list(&list(quote::quote!(known))).unwrap();
+    assert!(parsed.warnings.is_empty());
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(lenient), unknown_fields = "allow")]
+#[allow(dead_code)]
+struct LenientOuter {
+    strict: Deny,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(strict))]
+struct StrictOuter {
+    #[darling(flatten)]
+    lenient: Allow,
+}
+
+#[test]
+fn strict_inside_lenient() {
+    LenientOuter::from_derive_input(&parse_quote! {
+        #[lenient(strict(known), extra)]
+        struct Foo;
+    })
+    .unwrap();
+
+    let err = LenientOuter::from_derive_input(&parse_quote! {
+        #[lenient(strict(known, extra))]
+        struct Foo;
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unknown field: `extra`. Available values: `known` at strict"
+    );
+}
+
+#[test]
+fn flatten_overrides_parent() {
+    let parsed = StrictOuter::from_derive_input(&parse_quote! {
+        #[strict(known, extra)]
+        struct Foo;
+    })
+    .unwrap();
+
+    assert!(parsed.lenient.known);
+}
+
+#[test]
+fn legacy_allow_unknown_fields() {
+    #[derive(Debug, FromMeta)]
+    #[darling(allow_unknown_fields)]
+    struct Legacy {
+        known: bool,
+    }
+
+    assert!(
+        Legacy::from_list(&list(quote::quote!(known, extra)))
+            .unwrap()
+            .known
+    );
+}
+
+#[test]
+fn enum_variants_inherit() {
+    #[derive(Debug, FromMeta)]
+    #[darling(unknown_fields = "warn")]
+    #[allow(dead_code)]
+    enum Shape {
+        Circle {
+            radius: u8,
+            #[darling(warnings)]
+            warnings: Vec<darling::Error>,
+        },
+    }
+
+    let Shape::Circle { warnings, .. } =
+        Shape::from_list(&list(quote::quote!(circle(radius = 1, color = "red")))).unwrap();
+    assert_eq!(warnings.len(), 1);
+}
@@ -0,0 +1,60 @@
+use darling::{util::Visibility, FromDeriveInput, FromField};
+use syn::parse_quote;
+
+#[derive(FromDeriveInput)]
+struct Container {
+    vis: Visibility,
+}
+
+#[derive(FromField)]
+struct Field {
+    vis: Visibility,
+}
+
+/// The raw `syn` type must keep working for existing callers.
+#[derive(FromField)]
+struct SynField {
+    vis: syn::Visibility,
+}
+
+#[test]
+fn container_visibility() {
+    let di = parse_quote! {
+        pub(crate) struct Foo;
+    };
+
+    assert_eq!(
+        Container::from_derive_input(&di).unwrap().vis,
+        Visibility::Crate
+    );
+}
+
+#[test]
+fn field_visibility() {
+    let fields: syn::FieldsNamed = parse_quote!({
+        pub a: u8,
+        pub(in foo::bar) b: u8,
+        c: u8,
+    });
+    let fields = fields
+        .named
+        .iter()
+        .map(|f| Field::from_field(f).unwrap().vis)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        fields,
+        vec![
+            Visibility::Public,
+            Visibility::Restricted(parse_quote!(foo::bar)),
+            Visibility::Inherited,
+        ]
+    );
+}
+
+#[test]
+fn syn_visibility_still_supported() {
+    let field: syn::FieldsNamed = parse_quote!({ pub(super) a: u8 });
+    let vis = SynField::from_field(&field.named[0]).unwrap().vis;
+    assert_eq!(vis, parse_quote!(pub(super)));
+}